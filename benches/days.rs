@@ -0,0 +1,185 @@
+//! Times parse, part 1 and part 2 of every day on the demo input, a generated
+//! input and, when one can be found, the real input.
+//!
+//! Medians are stored in `target/aoc-bench.txt` and the next run prints the
+//! change against them.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::time::{Duration, Instant};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use aoc_2025::{input, Part, Report, Solver, DAYS};
+
+const RESULTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/aoc-bench.txt");
+
+/// Each input is solved at least this many times...
+const MIN_RUNS: usize = 3;
+/// ...and then repeated until this much time was spent on it.
+const BUDGET: Duration = Duration::from_millis(500);
+
+fn main() -> anyhow::Result<()> {
+    let previous = load_previous();
+    let mut rng = StdRng::seed_from_u64(2025);
+    let mut saved = String::new();
+
+    println!(
+        "{:>3}  {:<9}  {:<5}  {:>12}  {:>12}  {:>8}",
+        "day", "input", "step", "median", "previous", "change",
+    );
+    for (day, solve) in DAYS {
+        let mut inputs = vec![
+            ("demo", fs::read_to_string(format!("{}/src/day{:02}/examples/demo.txt", env!("CARGO_MANIFEST_DIR"), day))?),
+            ("generated", generate(day, &mut rng)),
+        ];
+        if let Ok(real) = input::load(day, None) {
+            inputs.push(("real", real));
+        }
+
+        for (name, input) in inputs {
+            for (step, median) in measure(solve, &input)? {
+                let key = format!("{} {} {}", day, name, step);
+                let before = previous.get(&key).copied();
+                let change = before
+                    .map(|b| format!("{:+.1}%", (median.as_secs_f64() / b.as_secs_f64() - 1.0) * 100.0))
+                    .unwrap_or_default();
+
+                println!(
+                    "{:>3}  {:<9}  {:<5}  {:>12.3?}  {:>12}  {:>8}",
+                    day,
+                    name,
+                    step,
+                    median,
+                    before.map(|b| format!("{:.3?}", b)).unwrap_or_default(),
+                    change,
+                );
+                writeln!(saved, "{} {}", key, median.as_nanos())?;
+            }
+        }
+    }
+
+    fs::write(RESULTS, saved)?;
+    Ok(())
+}
+
+/// Median time of parse, part 1 and part 2.
+fn measure(solve: Solver, input: &str) -> anyhow::Result<Vec<(&'static str, Duration)>> {
+    let mut reports: Vec<Report> = vec![];
+    let start = Instant::now();
+    while reports.len() < MIN_RUNS || start.elapsed() < BUDGET {
+        reports.push(solve(input, &Part::ALL)?);
+    }
+
+    let median = |step: fn(&Report) -> Duration| {
+        let mut times: Vec<_> = reports.iter().map(step).collect();
+        times.sort();
+        times[times.len() / 2]
+    };
+
+    Ok(vec![
+        ("parse", median(|r| r.parse)),
+        ("part1", median(|r| r.parts[0].elapsed)),
+        ("part2", median(|r| r.parts[1].elapsed)),
+    ])
+}
+
+fn load_previous() -> HashMap<String, Duration> {
+    fs::read_to_string(RESULTS)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| {
+            let (key, nanos) = l.rsplit_once(' ')?;
+            Some((key.to_string(), Duration::from_nanos(nanos.parse().ok()?)))
+        })
+        .collect()
+}
+
+/// Random input of roughly real size, shaped so every solver accepts it.
+fn generate(day: u8, rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    match day {
+        1 => {
+            for _ in 0..100_000 {
+                let dir = if rng.gen_bool(0.5) { 'L' } else { 'R' };
+                writeln!(out, "{}{}", dir, rng.gen_range(1..1000)).unwrap();
+            }
+        }
+        2 => {
+            let ranges: Vec<_> = (0..20).map(|_| {
+                let start = rng.gen_range(1..1_000_000_000u64);
+                format!("{}-{}", start, start + rng.gen_range(0..10_000))
+            }).collect();
+            out = ranges.join(",");
+        }
+        3 => {
+            for _ in 0..200 {
+                let bank: String = (0..100).map(|_| char::from(b'0' + rng.gen_range(1..=9))).collect();
+                writeln!(out, "{}", bank).unwrap();
+            }
+        }
+        4 => {
+            for _ in 0..140 {
+                let row: String = (0..140).map(|_| if rng.gen_bool(0.6) { '@' } else { '.' }).collect();
+                writeln!(out, "{}", row).unwrap();
+            }
+        }
+        5 => {
+            for _ in 0..200 {
+                let start = rng.gen_range(1..1_000_000_000_000u64);
+                writeln!(out, "{}-{}", start, start + rng.gen_range(0..10_000_000_000)).unwrap();
+            }
+            writeln!(out).unwrap();
+            for _ in 0..1000 {
+                writeln!(out, "{}", rng.gen_range(1..1_000_000_000_000u64)).unwrap();
+            }
+        }
+        6 => {
+            // blocks of equally wide numbers, one blank column apart
+            let widths: Vec<usize> = (0..1000).map(|_| rng.gen_range(1..=4)).collect();
+            for _ in 0..3 {
+                let row: Vec<String> = widths.iter().map(|&w| {
+                    (0..w).map(|_| char::from(b'0' + rng.gen_range(1..=9))).collect()
+                }).collect();
+                writeln!(out, "{}", row.join(" ")).unwrap();
+            }
+            let ops: Vec<String> = widths.iter().map(|&w| {
+                format!("{:<w$}", if rng.gen_bool(0.5) { '+' } else { '*' })
+            }).collect();
+            writeln!(out, "{}", ops.join(" ")).unwrap();
+        }
+        7 => {
+            // splitters only where a beam arrives, as in the real input
+            let width = 141;
+            let mut beams = vec![false; width];
+            beams[width / 2] = true;
+            let mut start = vec!['.'; width];
+            start[width / 2] = 'S';
+            writeln!(out, "{}", start.iter().collect::<String>()).unwrap();
+
+            for _ in 0..70 {
+                let mut row = vec!['.'; width];
+                let mut next = beams.clone();
+                for x in 1..width - 1 {
+                    if beams[x] && row[x - 1] != '^' && rng.gen_bool(0.3) {
+                        row[x] = '^';
+                        next[x] = false;
+                        next[x - 1] = true;
+                        next[x + 1] = true;
+                    }
+                }
+                beams = next;
+                writeln!(out, "{}", ".".repeat(width)).unwrap();
+                writeln!(out, "{}", row.iter().collect::<String>()).unwrap();
+            }
+        }
+        8 => {
+            for _ in 0..1000 {
+                let [x, y, z] = [(); 3].map(|_| rng.gen_range(0..100_000));
+                writeln!(out, "{},{},{}", x, y, z).unwrap();
+            }
+        }
+        _ => unreachable!("no generator for day {}", day),
+    }
+    out
+}
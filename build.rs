@@ -0,0 +1,67 @@
+//! Generates one test per part of every `src/dayNN/examples/NAME.txt` that has
+//! a `NAME.answers` next to it, see `tests/examples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut out = String::new();
+
+    let mut days: Vec<_> = fs::read_dir(Path::new(&root).join("src"))
+        .unwrap()
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.join("examples").is_dir())
+        .collect();
+    days.sort();
+
+    for day in days {
+        let module = day.file_name().unwrap().to_str().unwrap().to_string();
+        let examples = day.join("examples");
+        println!("cargo:rerun-if-changed={}", examples.display());
+
+        let mut inputs: Vec<_> = fs::read_dir(&examples)
+            .unwrap()
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+            .collect();
+        inputs.sort();
+
+        for input in inputs {
+            let answers = input.with_extension("answers");
+            println!("cargo:rerun-if-changed={}", answers.display());
+            let Ok(answers) = fs::read_to_string(&answers) else {
+                panic!("{} has no {}", input.display(), answers.display())
+            };
+
+            let name: String = input
+                .file_stem().unwrap().to_str().unwrap()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+                .collect();
+
+            for line in answers.lines().filter(|l| !l.trim().is_empty()) {
+                let Some((part, answer)) = line.trim().split_once(' ') else {
+                    panic!("expected `part answer` in {}, got {:?}", input.display(), line)
+                };
+                let variant = match part {
+                    "1" => "One",
+                    "2" => "Two",
+                    _ => panic!("no part {} in {}", part, input.display()),
+                };
+
+                writeln!(
+                    out,
+                    "example!({module}_{name}_part{part}, {module}::Day{day}, {variant}, {path:?}, {answer:?});",
+                    day = &module[3..],
+                    path = input.display().to_string(),
+                    answer = answer.trim(),
+                ).unwrap();
+            }
+        }
+    }
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs"), out).unwrap();
+}
@@ -0,0 +1,106 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+// live bytes since counting was enabled, negative once blocks from before are freed
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// System allocator keeping count of allocations and live bytes, install with
+/// `#[global_allocator]` and [`enable`] it to get non-zero [`Usage`] out of
+/// [`measure`]. Until then it is the plain system allocator.
+pub struct Counting;
+
+/// Starts counting, so timings are not charged for it unless asked.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() && enabled() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        if enabled() {
+            CURRENT.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() && enabled() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() && enabled() {
+            CURRENT.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+            grow(new_size);
+        }
+        new
+    }
+}
+
+fn grow(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let now = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+/// Memory used by a single step; all zero unless [`Counting`] is installed and enabled.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct Usage {
+    /// Allocations and reallocations made.
+    pub allocations: u64,
+    /// Most bytes live at once, above what was live before the step.
+    pub peak: usize,
+}
+
+/// Runs `f`, recording the allocations it makes.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let result = f();
+
+    (result, Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        peak: (PEAK.load(Ordering::Relaxed) - base).max(0) as usize,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (len, usage) = measure(|| {
+            let v = vec![0u8; 4096];
+            v.len()
+        });
+
+        assert_eq!(len, 4096);
+        assert!(usage.allocations >= 1);
+        assert!(usage.peak >= 4096);
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::fs;
+use crate::{Part, Report};
+
+/// Accepted answers checked in at the repository root.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+/// Recorded answers keyed by day, part and [`input_hash`](crate::input_hash) of the input.
+///
+/// One answer per line as `day part input_hash answer`; blank lines and lines
+/// starting with `#` are skipped.
+#[derive(Debug, Default)]
+pub struct Answers {
+    known: HashMap<(u8, Part, String), String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> anyhow::Result<Answers> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| anyhow::format_err!("cannot read {}: {}", path, err))?;
+
+        Answers::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> anyhow::Result<Answers> {
+        let mut known = HashMap::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [day, part, hash, answer] = fields[..] else {
+                anyhow::bail!("line {}: expected `day part input_hash answer`, got {:?}", i + 1, line)
+            };
+            let part = Part::try_from(part.parse::<u8>()?)?;
+
+            known.insert((day.parse()?, part, hash.to_string()), answer.to_string());
+        }
+
+        Ok(Answers { known })
+    }
+
+    pub fn get(&self, day: u8, part: Part, hash: &str) -> Option<&str> {
+        self.known.get(&(day, part, hash.to_string())).map(String::as_str)
+    }
+
+    /// Compares every part of `report` to the recorded answers.
+    pub fn check(&self, report: &Report) -> Vec<(Part, Status)> {
+        report.parts.iter().map(|result| {
+            let status = match self.get(report.day, result.part, &report.input_hash) {
+                None => Status::Missing,
+                Some(expected) if expected == result.answer => Status::Pass,
+                Some(expected) => Status::Fail { expected: expected.to_string() },
+            };
+            (result.part, status)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::alloc::Usage;
+    use crate::{input_hash, PartResult};
+    use super::*;
+
+    const ANSWERS: &str = "\
+# day part input answer
+1 1 0123456789abcdef 1052
+
+1 2 0123456789abcdef 6295
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(1, Part::One, "0123456789abcdef"), Some("1052"));
+        assert_eq!(answers.get(1, Part::Two, "0123456789abcdef"), Some("6295"));
+        assert_eq!(answers.get(1, Part::Two, "fedcba9876543210"), None);
+        assert!(Answers::parse("1 1 1052").is_err());
+        assert!(Answers::parse("1 3 0123456789abcdef 1052").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let hash = input_hash("L68\n");
+        let answers = Answers::parse(&format!("1 1 {} 1052", hash)).unwrap();
+        let result = |part, answer: &str| PartResult { part, answer: answer.into(), elapsed: Duration::ZERO, usage: Usage::default() };
+
+        let report = Report { day: 1, input_hash: hash.clone(), parse: Duration::ZERO, parse_usage: Usage::default(), parts: vec![result(Part::One, "1052"), result(Part::Two, "1")] };
+        assert_eq!(answers.check(&report), vec![(Part::One, Status::Pass), (Part::Two, Status::Missing)]);
+
+        let report = Report { day: 1, input_hash: hash, parse: Duration::ZERO, parse_usage: Usage::default(), parts: vec![result(Part::One, "1053")] };
+        assert_eq!(answers.check(&report), vec![(Part::One, Status::Fail { expected: "1052".into() })]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use super::{solve2, Cmd, Dial, Position};
+
+/// Where the dial ends up after a number of passes over a command list, with
+/// the zeroes of all passes together, which may well not fit in a `u64`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Repeated {
+    pub position: Position,
+    /// Commands ending at zero, as part 1 counts.
+    pub landed: u128,
+    /// Clicks pointing at zero, as part 2 counts.
+    pub passed: u128,
+}
+
+/// Runs `cmds` `times` times in a row. A pass only depends on the position it
+/// starts at, so at most `dial.size` passes are run before the entry positions
+/// repeat and the rest follows from the cycle.
+pub fn repeat(dial: &Dial, cmds: &[Cmd], times: u64) -> Repeated {
+    // totals before each pass, and the pass each entry position was first seen at
+    let mut history = vec![Repeated { position: dial.start, landed: 0, passed: 0 }];
+    let mut seen: HashMap<Position, usize> = HashMap::new();
+
+    loop {
+        let pass = history.len() - 1;
+        let now = history[pass];
+        if pass as u64 == times {
+            return now;
+        }
+
+        if let Some(&first) = seen.get(&now.position) {
+            let start = history[first];
+            let len = (pass - first) as u64;
+            let left = times - pass as u64;
+            let tail = history[first + (left % len) as usize];
+            let cycles = (left / len) as u128;
+
+            return Repeated {
+                position: tail.position,
+                landed: now.landed + cycles * (now.landed - start.landed) + tail.landed - start.landed,
+                passed: now.passed + cycles * (now.passed - start.passed) + tail.passed - start.passed,
+            };
+        }
+        seen.insert(now.position, pass);
+
+        let next = solve2(&Dial { start: now.position, ..*dial }, cmds).fold(now, |acc, (pos, zeroes)| Repeated {
+            position: pos,
+            landed: acc.landed + (pos == 0) as u128,
+            passed: acc.passed + zeroes as u128,
+        });
+        history.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use crate::day01::{landed_zeroes, parse, passed_zeroes, random_program, solve};
+    use super::*;
+
+    /// Every pass replayed.
+    fn replayed(dial: &Dial, cmds: &[Cmd], times: u64) -> Repeated {
+        let all: Vec<_> = (0..times).flat_map(|_| cmds.iter().copied()).collect();
+        Repeated {
+            position: solve(dial, &all).last().unwrap_or(dial.start),
+            landed: landed_zeroes(dial, &all).into(),
+            passed: passed_zeroes(dial, &all).into(),
+        }
+    }
+
+    #[test]
+    fn test_demo() {
+        let dial = Dial::default();
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+
+        assert_eq!(repeat(&dial, &cmds, 0), Repeated { position: 50, landed: 0, passed: 0 });
+        assert_eq!(repeat(&dial, &cmds, 1), Repeated { position: 32, landed: 3, passed: 6 });
+        assert_eq!(repeat(&dial, &cmds, 123), replayed(&dial, &cmds, 123));
+    }
+
+    #[test]
+    fn test_trillion() {
+        let dial = Dial::new(100, 0).unwrap();
+        let passes = 1_000_000_000_000;
+
+        assert_eq!(
+            repeat(&dial, &[Cmd::Right(1)], passes),
+            Repeated { position: 0, landed: passes as u128 / 100, passed: passes as u128 / 100 },
+        );
+        assert_eq!(
+            repeat(&dial, &[Cmd::Left(3), Cmd::Set(0)], passes),
+            Repeated { position: 0, landed: passes as u128, passed: passes as u128 },
+        );
+
+        // twenty million zeroes a pass, past `u64::MAX` in total
+        let repeated = repeat(&Dial::default(), &[Cmd::Right(2_000_000_000)], passes);
+        assert_eq!(repeated, Repeated { position: 50, landed: 0, passed: 20_000_000_000_000_000_000 });
+        assert!(repeated.passed > u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_matches_replay() {
+        let mut rng = StdRng::seed_from_u64(20);
+
+        for _ in 0..200 {
+            let (dial, cmds) = random_program(&mut rng, 6, 2);
+            let times = rng.gen_range(0..100);
+
+            assert_eq!(repeat(&dial, &cmds, times), replayed(&dial, &cmds, times), "{:?} on {:?}", cmds, dial);
+        }
+    }
+}
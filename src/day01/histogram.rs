@@ -0,0 +1,124 @@
+use std::fmt::{Display, Formatter};
+use super::{table_size, Cmd, Dial, Position};
+
+/// How often every position of the dial was landed on by a command and how
+/// often a command swept past it without stopping.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Histogram {
+    pub landed: Vec<u64>,
+    pub passed: Vec<u64>,
+}
+
+impl Histogram {
+    /// Works per command rather than per click, so distances in the millions are fine;
+    /// fails for dials above [`super::MAX_TABLE`] positions.
+    pub fn new(dial: &Dial, cmds: &[Cmd]) -> anyhow::Result<Histogram> {
+        let size = table_size(dial)?;
+        let mut landed = vec![0; size];
+        // difference array of the partial turns, full turns add to every position
+        let mut arcs = vec![0i64; size + 1];
+        let mut full_turns = 0;
+
+        let mut pos = dial.start;
+        for cmd in cmds {
+            let (step, first) = match *cmd {
+                Cmd::Left(step) => (step, None),
+                Cmd::Right(step) => (step, Some(pos + 1)),
+                Cmd::Set(_) => (0, None),
+            };
+
+            let swept = step.saturating_sub(1);
+            full_turns += swept / dial.size;
+            let rem = swept % dial.size;
+            if rem > 0 {
+                // clicks 1..=rem are a contiguous run of positions, maybe wrapping
+                let from = first.unwrap_or(pos + dial.size - rem) % dial.size;
+                add_arc(&mut arcs, from, rem, dial.size);
+            }
+
+            pos = cmd.apply(dial, &pos);
+            landed[pos as usize] += 1;
+        }
+
+        let passed = arcs[..size]
+            .iter()
+            .scan(0i64, |acc, d| {
+                *acc += d;
+                Some(*acc as u64 + full_turns)
+            })
+            .collect();
+
+        Ok(Histogram { landed, passed })
+    }
+}
+
+fn add_arc(arcs: &mut [i64], from: Position, len: u64, size: u64) {
+    let end = from + len;
+    arcs[from as usize] += 1;
+    if end <= size {
+        arcs[end as usize] -= 1;
+    } else {
+        arcs[size as usize] -= 1;
+        arcs[0] += 1;
+        arcs[(end - size) as usize] -= 1;
+    }
+}
+
+impl Display for Histogram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:>8}  {:>10}  {:>10}", "position", "landed", "passed")?;
+        for (pos, (landed, passed)) in self.landed.iter().zip(&self.passed).enumerate() {
+            writeln!(f, "{:>8}  {:>10}  {:>10}", pos, landed, passed)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::day01::{parse, random_program, solve, solve2};
+    use super::*;
+
+    /// Counts the same thing click by click.
+    fn simulated(dial: &Dial, cmds: &[Cmd]) -> Histogram {
+        let size = dial.size as usize;
+        let mut histogram = Histogram { landed: vec![0; size], passed: vec![0; size] };
+
+        let mut pos = dial.start;
+        for cmd in cmds {
+            let clicks: Vec<_> = cmd.clicks(dial, &pos).collect();
+            if let Some((&last, swept)) = clicks.split_last() {
+                swept.iter().for_each(|&p| histogram.passed[p as usize] += 1);
+                pos = last;
+            }
+            histogram.landed[pos as usize] += 1;
+        }
+
+        histogram
+    }
+
+    #[test]
+    fn test_demo() {
+        let dial = Dial::default();
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+        let histogram = Histogram::new(&dial, &cmds).unwrap();
+
+        assert_eq!(histogram.landed[0] as usize, solve(&dial, &cmds).filter(|&p| p == 0).count());
+        assert_eq!(histogram.landed[0] + histogram.passed[0], solve2(&dial, &cmds).map(|(_, c)| c).sum());
+        assert_eq!(histogram.landed.iter().sum::<u64>(), cmds.len() as u64);
+        assert!(Histogram::new(&Dial::new(100_000_000_000, 0).unwrap(), &cmds).is_err());
+    }
+
+    #[test]
+    fn test_matches_simulation() {
+        let mut rng = StdRng::seed_from_u64(13);
+
+        for _ in 0..500 {
+            let (dial, cmds) = random_program(&mut rng, 30, 4);
+
+            assert_eq!(Histogram::new(&dial, &cmds).unwrap(), simulated(&dial, &cmds), "{:?} on {:?}", cmds, dial);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Range;
+use super::{solve2, Cmd, Dial, Position};
+
+/// Prefix positions and zero counts of a command list, answering queries about
+/// any stretch of it in constant time.
+#[derive(Debug)]
+pub struct Index {
+    /// Position after the first `i` commands, the start at 0.
+    positions: Vec<Position>,
+    /// Commands ending at zero among the first `i`.
+    landed: Vec<u64>,
+    /// Clicks pointing at zero during the first `i` commands.
+    passed: Vec<u64>,
+}
+
+impl Index {
+    pub fn new(dial: &Dial, cmds: &[Cmd]) -> Index {
+        let mut index = Index {
+            positions: Vec::with_capacity(cmds.len() + 1),
+            landed: Vec::with_capacity(cmds.len() + 1),
+            passed: Vec::with_capacity(cmds.len() + 1),
+        };
+        index.positions.push(dial.start);
+        index.landed.push(0);
+        index.passed.push(0);
+
+        for (pos, zeroes) in solve2(dial, cmds) {
+            index.positions.push(pos);
+            index.landed.push(index.landed.last().unwrap() + (pos == 0) as u64);
+            index.passed.push(index.passed.last().unwrap() + zeroes);
+        }
+
+        index
+    }
+
+    /// Number of commands indexed.
+    pub fn len(&self) -> usize {
+        self.positions.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Position after the first `i` commands; `None` past the end.
+    pub fn position(&self, i: usize) -> Option<Position> {
+        self.positions.get(i).copied()
+    }
+
+    /// How many of the commands `range` (0-based) end at zero, as part 1 counts.
+    /// Panics like slicing when `range` is out of bounds.
+    pub fn landed(&self, range: Range<usize>) -> u64 {
+        self.landed[range.end] - self.landed[range.start]
+    }
+
+    /// How many clicks of the commands `range` point at zero, as part 2 counts.
+    pub fn passed(&self, range: Range<usize>) -> u64 {
+        self.passed[range.end] - self.passed[range.start]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use crate::day01::{landed_zeroes, parse, passed_zeroes, random_program};
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let dial = Dial::default();
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+        let index = Index::new(&dial, &cmds);
+
+        assert_eq!(index.len(), cmds.len());
+        assert_eq!(index.position(0), Some(dial.start));
+        assert_eq!(index.position(1), Some(82));
+        assert_eq!(index.position(cmds.len() + 1), None);
+        assert_eq!(index.landed(0..cmds.len()), landed_zeroes(&dial, &cmds));
+        assert_eq!(index.passed(0..cmds.len()), passed_zeroes(&dial, &cmds));
+    }
+
+    #[test]
+    fn test_ranges_match_rerun() {
+        let mut rng = StdRng::seed_from_u64(15);
+
+        for _ in 0..200 {
+            let (dial, cmds) = random_program(&mut rng, 40, 3);
+            let index = Index::new(&dial, &cmds);
+
+            let i = rng.gen_range(0..=cmds.len());
+            let j = rng.gen_range(i..=cmds.len());
+            let from = Dial::new(dial.size, index.position(i).unwrap()).unwrap();
+            assert_eq!(index.position(j), Some(dial.start).into_iter().chain(solve2(&dial, &cmds).map(|(p, _)| p)).nth(j));
+            assert_eq!(index.landed(i..j), landed_zeroes(&from, &cmds[i..j]));
+            assert_eq!(index.passed(i..j), passed_zeroes(&from, &cmds[i..j]));
+        }
+    }
+}
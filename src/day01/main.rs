@@ -1,88 +1,179 @@
-use std::fs;
-
-
-type Position = u16;
-#[derive(Debug, PartialEq, Eq)]
-enum Cmd {
-    Left(u16),
-    Right(u16),
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+use clap::{Parser, Subcommand};
+use aoc_2025::day01::cycle;
+use aoc_2025::day01::histogram::Histogram;
+use aoc_2025::day01::index::Index;
+use aoc_2025::day01::odometer::{Carry, Odometer, Wheel};
+use aoc_2025::day01::planner::{self, Turns};
+use aoc_2025::day01::render;
+use aoc_2025::day01::sensitivity::Sensitivity;
+use aoc_2025::day01::stream;
+use aoc_2025::day01::{self, Cmd, Day01, Dial};
+use aoc_2025::{input, logger, Solution};
+
+/// Day 1: the dial of the safe. Without a mode, solves the looked up input.
+#[derive(Parser)]
+#[command(name = "day01")]
+struct Cli {
+    #[command(subcommand)]
+    mode: Option<Mode>,
+    /// Positions on the dial.
+    #[arg(long, global = true, default_value_t = day01::DOMAIN)]
+    size: u64,
+    /// Position before the first command.
+    #[arg(long, global = true, default_value_t = day01::START)]
+    start: u64,
+    /// Diagnostics on stderr: `-v` for debug, `-vv` for trace.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
+#[derive(Subcommand)]
+enum Mode {
+    /// Prints both answers.
+    Solve {
+        /// Input file, `-` for stdin; looked up when omitted.
+        input: Option<String>,
+    },
+    /// Draws the dial turning click by click, marking every click on zero.
+    Animate {
+        input: Option<String>,
+        /// Clicks per second.
+        #[arg(long, default_value_t = 30.0)]
+        speed: f64,
+        /// Rows from the centre of the ring to the ticks.
+        #[arg(long, default_value_t = 10)]
+        radius: usize,
+        /// Write each frame to DIR/frame-NNNNNN.txt instead of the terminal.
+        #[arg(long, value_name = "DIR")]
+        frames: Option<PathBuf>,
+    },
+    /// How often each position was landed on and swept past.
+    Histogram {
+        input: Option<String>,
+    },
+    /// Both answers for every start position: best, worst and distribution.
+    Starts {
+        input: Option<String>,
+    },
+    /// Stacks more wheels on the dial, each turned by the carries of the one below.
+    Odometer {
+        input: Option<String>,
+        /// Sizes of the wheels above the dial, all starting at 0.
+        #[arg(long, value_delimiter = ',', default_value = "10")]
+        wheels: Vec<u64>,
+        /// Carry rule of each wheel, `zero`, `wrap` or `landed`; the last one repeats.
+        #[arg(long, value_delimiter = ',', default_value = "zero")]
+        carry: Vec<Carry>,
+    },
+    /// Prints the fewest clicks reaching each of TARGETS in turn, as commands.
+    Plan {
+        #[arg(value_delimiter = ',', required = true)]
+        targets: Vec<u64>,
+        /// `shortest` way each time, or alternating `rl` or `lr`.
+        #[arg(long, default_value = "shortest")]
+        turns: Turns,
+    },
+    /// Runs the commands TIMES times over, as many as fit in 64 bits.
+    Repeat {
+        times: u64,
+        input: Option<String>,
+    },
+    /// Reads commands from stdin as they come, printing `position landed passed` after each.
+    Stream,
+    /// Position after command TO and the zeroes of commands FROM+1..=TO.
+    Query {
+        from: usize,
+        to: usize,
+        input: Option<String>,
+    },
+}
 
-const START: Position = 50;
-const DOMAIN: Position = 100;
-
-impl Cmd {
-    fn from_str(s: &str) -> Option<Cmd> {
-        let (dir, dist) = s.split_at(1);
-        let dist: u16 = dist.parse().ok()?;
-        match dir {
-            "L" => Some(Cmd::Left(dist)),
-            "R" => Some(Cmd::Right(dist)),
-            _ => None,
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    logger::init(cli.verbose);
+    let dial = Dial::new(cli.size, cli.start)?;
+
+    match cli.mode.unwrap_or(Mode::Solve { input: None }) {
+        Mode::Solve { input } => {
+            let cmds = load(&dial, input)?;
+            println!("{}", day01::landed_zeroes(&dial, &cmds));
+            println!("{}", day01::passed_zeroes(&dial, &cmds));
         }
-    }
+        Mode::Animate { input, speed, radius, frames } => {
+            if speed.is_nan() || speed <= 0.0 {
+                anyhow::bail!("speed must be a positive number of clicks per second");
+            }
+            let delay = Duration::try_from_secs_f64(1.0 / speed)
+                .map_err(|_| anyhow::format_err!("speed {:?} is too slow to wait between clicks", speed))?;
+            let cmds = load(&dial, input)?;
+            if let Some(dir) = &frames {
+                std::fs::create_dir_all(dir)?;
+            }
 
-    fn apply(&self, state: &Position) -> Position {
-        match self {
-            Cmd::Left(step) => (state+DOMAIN - (step % DOMAIN)) % DOMAIN,
-            Cmd::Right(step) => (state.wrapping_add(DOMAIN).wrapping_add(step % DOMAIN)) % DOMAIN,
+            for (n, frame) in render::frames(&dial, &cmds).enumerate() {
+                let drawing = render::draw(&dial, &frame, radius);
+                match &frames {
+                    Some(dir) => std::fs::write(dir.join(format!("frame-{:06}.txt", n)), drawing)?,
+                    None => {
+                        // clear the screen and start over at the top
+                        print!("\x1b[2J\x1b[H{}", drawing);
+                        sleep(delay);
+                    }
+                }
+            }
         }
-    }
-
-    fn apply_zero_counting(&self, state: &Position) -> (Position, u16) {
-        match &self {
-            Cmd::Left(step) => {
-                let diff = (*state as i16) - (*step as i16);
-
-                // bleh
-                (self.apply(state), if *state != 0 && diff <= 0 {1} else {0} + (diff.abs() as u16) / DOMAIN)
+        Mode::Histogram { input } => {
+            print!("{}", Histogram::new(&dial, &load(&dial, input)?)?);
+        }
+        Mode::Starts { input } => {
+            print!("{}", Sensitivity::new(&dial, &load(&dial, input)?)?);
+        }
+        Mode::Odometer { input, wheels, carry } => {
+            let dials = std::iter::once(Ok(dial))
+                .chain(wheels.into_iter().map(|size| Dial::new(size, 0)))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let wheels = dials.into_iter().enumerate()
+                .map(|(i, dial)| Wheel { dial, carry: carry[i.min(carry.len() - 1)] })
+                .collect();
+
+            let mut odometer = Odometer::new(wheels);
+            load(&dial, input)?.iter().for_each(|cmd| odometer.push(cmd));
+            print!("{}", odometer);
+        }
+        Mode::Plan { targets, turns } => {
+            for cmd in planner::plan(&dial, &targets, turns)? {
+                println!("{}", cmd);
+            }
+        }
+        Mode::Repeat { times, input } => {
+            let repeated = cycle::repeat(&dial, &load(&dial, input)?, times);
+            println!("position {}", repeated.position);
+            println!("landed {}", repeated.landed);
+            println!("passed {}", repeated.passed);
+        }
+        Mode::Stream => {
+            stream::stream(dial, std::io::stdin().lock(), std::io::stdout().lock())?;
+        }
+        Mode::Query { from, to, input } => {
+            let index = Index::new(&dial, &load(&dial, input)?);
+            if from > to || to > index.len() {
+                anyhow::bail!("need 0 <= FROM <= TO <= {}", index.len());
             }
-            Cmd::Right(step) => (self.apply(state), (state + step) / DOMAIN),
+            println!("position {}", index.position(to).unwrap());
+            println!("landed {}", index.landed(from..to));
+            println!("passed {}", index.passed(from..to));
         }
     }
-}
-
-fn main() -> anyhow::Result<()> {
-    let input = fs::read_to_string("src/day01/input")
-        .map_err(|err| anyhow::anyhow!(err))?;
-
-    println!(
-        "{}",
-        solve(&input).filter_map(|p| if p == 0 {Some(p)} else {None}).count()
-    );
-    println!(
-        "{}",
-        solve2(&input).map(|(_, c)| c).sum::<u16>(),
-    );
 
     Ok(())
 }
 
-fn solve(input: &str) -> impl Iterator<Item=Position> {
-    input
-        .lines()
-        .filter_map(|l| Cmd::from_str(l))
-        .scan(START, |state, cmd| {
-            *state = cmd.apply(state);
-            Some(*state)
-        })
-        .into_iter()
-}
-
-fn solve2(input: &str) -> impl Iterator<Item=(Position, u16)> {
-    input
-        .lines()
-        .filter_map(|l| Cmd::from_str(l))
-        .scan((START, 0), |(pos, _), cmd| {
-            let (new_pos, new_zeroes) = cmd.apply_zero_counting(pos);
-            *pos = new_pos;
-            Some((
-                new_pos,
-                new_zeroes,
-            ))
-        })
-        .into_iter()
+/// The commands at `path`, checked against the `dial` they will turn.
+fn load(dial: &Dial, path: Option<String>) -> anyhow::Result<Vec<Cmd>> {
+    Ok(day01::parse_on(dial, &input::load(Day01::DAY, path.as_deref())?)?)
 }
 
 #[cfg(test)]
@@ -90,108 +181,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_cmd_from_str() {
-        assert_eq!(Cmd::from_str("L10"), Some(Cmd::Left(10)));
-        assert_eq!(Cmd::from_str("R20"), Some(Cmd::Right(20)));
-        assert_eq!(Cmd::from_str("X30"), None);
-    }
-
-    #[test]
-    fn test_cmd_apply() {
-        assert_eq!(Cmd::Left(10).apply(&50), 40);
-        assert_eq!(Cmd::Right(20).apply(&50), 70);
-        assert_eq!(Cmd::Left(60).apply(&50), 90);
-        assert_eq!(Cmd::Right(60).apply(&50), 10);
-
-
-        assert_eq!(Cmd::Left(68).apply(&50), 82);
-        assert_eq!(Cmd::Left(30).apply(&82), 52);
-        assert_eq!(Cmd::Right(52).apply(&48), 0);
-    }
-
-
-    #[test]
-    fn test_cmd_apply_zero_counting() {
-        assert_eq!(Cmd::Left(68).apply_zero_counting(&50), (82, 1));
-        assert_eq!(Cmd::Right(60).apply_zero_counting(&95), (55, 1));
-        assert_eq!(Cmd::Left(82).apply_zero_counting(&14), (32, 1));
-
-        assert_eq!(Cmd::Right(1000).apply_zero_counting(&50), (50, 10));
-
-        // assert_eq!(Cmd::Right(52).apply(48), 0);
+    fn test_flags_before_mode() {
+        let cli = Cli::try_parse_from(["day01", "--size", "10", "-v", "histogram", "input.txt"]).unwrap();
+        assert_eq!((cli.size, cli.verbose), (10, 1));
+        assert!(matches!(cli.mode, Some(Mode::Histogram { input: Some(ref path) }) if path == "input.txt"));
 
-        // 14 -  L82 -> 1
-        // 14 - L182 -> 2
-    }
+        let cli = Cli::try_parse_from(["day01", "--start", "3", "solve", "-"]).unwrap();
+        assert_eq!(cli.start, 3);
+        assert!(matches!(cli.mode, Some(Mode::Solve { input: Some(ref path) }) if path == "-"));
 
-    #[test]
-    fn text_example() {
-        let input = "\
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let result: Vec<Position> = solve(input).collect();
-        insta::assert_snapshot!(format!("{:?}", result), @"[82, 52, 0, 95, 55, 0, 99, 0, 14, 32]");
-    }
-    #[test]
-    fn text_example2() {
-        let input = "\
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let count: u16 = solve2(input).map(|(_, c)| c).sum();
-        insta::assert_snapshot!(count, @"6");
-    }
-    #[test]
-    fn text_example3() {
-        let input = "\
-L50
-R50
-";
-        let count: u16 = solve2(input).map(|(_, c)| c).sum();
-        insta::assert_snapshot!(count, @"1");
-    }
-    #[test]
-    fn text_example4() {
-        let input = "\
-L150
-L50
-";
-        let count: u16 = solve2(input).map(|(_, c)| c).sum();
-        insta::assert_snapshot!(count, @"2");
-    }
-    #[test]
-    fn text_example5() {
-        let input = "\
-L150
-R50
-";
-        let count: u16 = solve2(input).map(|(_, c)| c).sum();
-        insta::assert_snapshot!(count, @"2");
-    }
-    #[test]
-    fn text_example6() {
-        let input = "\
-R150
-L50
-";
-        let count: u16 = solve2(input).map(|(_, c)| c).sum();
-        insta::assert_snapshot!(count, @"2");
+        let cli = Cli::try_parse_from(["day01", "starts", "--size", "7"]).unwrap();
+        assert_eq!(cli.size, 7);
     }
 }
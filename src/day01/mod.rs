@@ -0,0 +1,433 @@
+use std::fmt::{Display, Formatter};
+use crate::error::number;
+use crate::{ParseError, Solution};
+
+pub mod cycle;
+pub mod histogram;
+pub mod index;
+pub mod odometer;
+pub mod planner;
+pub mod render;
+pub mod sensitivity;
+pub mod stream;
+pub mod summary;
+
+pub type Position = u64;
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cmd {
+    Left(u64),
+    Right(u64),
+    /// Jumps straight to a position without turning past anything; [`parse_on`]
+    /// rejects positions that are not on the dial.
+    Set(Position),
+}
+
+
+pub const START: Position = 50;
+pub const DOMAIN: u64 = 100;
+/// Most positions a dial may have for reports keeping a table per position.
+pub const MAX_TABLE: u64 = 1 << 20;
+
+/// A dial of `size` positions, `0..size`, pointing at `start` before the first command.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Dial {
+    pub size: u64,
+    pub start: Position,
+}
+
+impl Dial {
+    pub fn new(size: u64, start: Position) -> anyhow::Result<Dial> {
+        if size == 0 {
+            anyhow::bail!("dial needs at least one position");
+        }
+        if start >= size {
+            anyhow::bail!("start {} is not on a dial of {} positions", start, size);
+        }
+        Ok(Dial { size, start })
+    }
+}
+
+/// `(state + clicks) % size`, widened so dials of up to `u64::MAX` positions
+/// do not overflow.
+pub(crate) fn turn(state: Position, clicks: u64, size: u64) -> Position {
+    ((state as u128 + clicks as u128) % size as u128) as Position
+}
+
+/// `dial.size` as the length of a table per position, when it is small enough to allocate.
+pub(crate) fn table_size(dial: &Dial) -> anyhow::Result<usize> {
+    if dial.size > MAX_TABLE {
+        anyhow::bail!("a dial of {} positions is too large to tabulate, at most {} are supported", dial.size, MAX_TABLE);
+    }
+    Ok(dial.size as usize)
+}
+
+impl Default for Dial {
+    /// The dial of the puzzle.
+    fn default() -> Self {
+        Dial { size: DOMAIN, start: START }
+    }
+}
+
+impl Cmd {
+    /// Parses `text`, a non-empty command found at 0-based `line` and `column`.
+    fn parse(line: usize, column: usize, text: &str) -> Result<Cmd, ParseError> {
+        let mut chars = text.chars();
+        let cmd = match chars.next() {
+            Some('L') => Cmd::Left,
+            Some('R') => Cmd::Right,
+            Some('S') => Cmd::Set,
+            _ => return Err(ParseError::new(Day01::DAY, line, column, text, "expected `L`, `R` or `S`")),
+        };
+        let digits = chars.as_str();
+        if digits.starts_with('+') {
+            return Err(ParseError::new(Day01::DAY, line, column + 1, digits, "expected digits without a sign"));
+        }
+        Ok(cmd(number(Day01::DAY, line, column + 1, digits)?))
+    }
+
+    pub fn apply(&self, dial: &Dial, state: &Position) -> Position {
+        let size = dial.size;
+        match self {
+            Cmd::Left(step) => turn(*state, size - step % size, size),
+            Cmd::Right(step) => turn(*state, step % size, size),
+            Cmd::Set(target) => target % size,
+        }
+    }
+
+    /// New position and how many times zero was passed or landed on on the way.
+    pub fn apply_zero_counting(&self, dial: &Dial, state: &Position) -> (Position, u64) {
+        let size = dial.size;
+        match &self {
+            Cmd::Left(step) => {
+                // first zero after `state` clicks, then one every full turn
+                let zeroes = if *state == 0 {
+                    step / size
+                } else if *step >= *state {
+                    1 + (step - state) / size
+                } else {
+                    0
+                };
+                (self.apply(dial, state), zeroes)
+            }
+            Cmd::Right(step) => {
+                let wrapped = (*state as u128 + (step % size) as u128 >= size as u128) as u64;
+                (self.apply(dial, state), step / size + wrapped)
+            }
+            Cmd::Set(_) => {
+                // a jump to zero points at it once, staying there does not
+                let pos = self.apply(dial, state);
+                (pos, (pos == 0 && *state != 0) as u64)
+            }
+        }
+    }
+
+    /// Every position the dial points at while turning from `state`, one click at a time;
+    /// a [`Cmd::Set`] is a single click straight to its target.
+    pub fn clicks(&self, dial: &Dial, state: &Position) -> impl Iterator<Item=Position> + use<> {
+        let (step, back) = match *self {
+            Cmd::Left(step) => (step, dial.size - 1),
+            Cmd::Right(step) => (step, 1),
+            Cmd::Set(_) => {
+                let target = self.apply(dial, state);
+                ((target != *state) as u64, turn(target, dial.size - state, dial.size))
+            }
+        };
+        let size = dial.size;
+        (0..step).scan(*state, move |pos, _| {
+            *pos = turn(*pos, back, size);
+            Some(*pos)
+        })
+    }
+
+    /// [`Cmd::apply_zero_counting`] the slow way, click by click.
+    pub fn simulate(&self, dial: &Dial, state: &Position) -> (Position, u64) {
+        self.clicks(dial, state).fold((*state, 0), |(_, zeroes), pos| {
+            (pos, zeroes + (pos == 0) as u64)
+        })
+    }
+}
+
+/// The input format, so `parse` reads back what is written.
+impl Display for Cmd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cmd::Left(step) => write!(f, "L{}", step),
+            Cmd::Right(step) => write!(f, "R{}", step),
+            Cmd::Set(target) => write!(f, "S{}", target),
+        }
+    }
+}
+
+/// A command of up to `max_step` clicks for the randomized tests, one in five
+/// a [`Cmd::Set`].
+#[cfg(test)]
+pub(crate) fn random_cmd(rng: &mut rand::rngs::StdRng, max_step: u64) -> Cmd {
+    use rand::Rng;
+
+    let step = rng.gen_range(0..=max_step);
+    match rng.gen_range(0..5) {
+        0 => Cmd::Set(step),
+        1 | 2 => Cmd::Left(step),
+        _ => Cmd::Right(step),
+    }
+}
+
+/// A dial of up to 50 positions pointing anywhere, and up to `max_len`
+/// [`random_cmd`]s of up to `step_factor` full turns each.
+#[cfg(test)]
+pub(crate) fn random_program(rng: &mut rand::rngs::StdRng, max_len: usize, step_factor: u64) -> (Dial, Vec<Cmd>) {
+    use rand::Rng;
+
+    let size = rng.gen_range(1..=50);
+    let dial = Dial::new(size, rng.gen_range(0..size)).unwrap();
+    let len = rng.gen_range(0..=max_len);
+    (dial, (0..len).map(|_| random_cmd(rng, step_factor * size)).collect())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Cmd>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_on(&Dial::default(), input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        landed_zeroes(&Dial::default(), input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        passed_zeroes(&Dial::default(), input)
+    }
+}
+
+/// One command per line: `L<n>` and `R<n>` turn the dial by `n` clicks, `S<n>`
+/// sets it to position `n`. Blank lines and anything after a `#` are ignored.
+pub fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(n, line)| parse_line(n, line).transpose())
+        .collect()
+}
+
+/// Parses the 0-based `n`th line of the input, `None` when it holds no command.
+pub fn parse_line(n: usize, line: &str) -> Result<Option<Cmd>, ParseError> {
+    let code = line.split('#').next().unwrap_or_default();
+    let cmd = code.trim();
+    let column = code[..code.len() - code.trim_start().len()].chars().count();
+    (!cmd.is_empty()).then(|| Cmd::parse(n, column, cmd)).transpose()
+}
+
+/// [`parse`] for a known `dial`, also rejecting [`Cmd::Set`] to positions not on it.
+pub fn parse_on(dial: &Dial, input: &str) -> Result<Vec<Cmd>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(n, line)| parse_line_on(dial, n, line).transpose())
+        .collect()
+}
+
+/// [`parse_line`] for a known `dial`, also rejecting a [`Cmd::Set`] to a position not on it.
+pub fn parse_line_on(dial: &Dial, n: usize, line: &str) -> Result<Option<Cmd>, ParseError> {
+    let cmd = parse_line(n, line)?;
+    if let Some(Cmd::Set(target)) = cmd && target >= dial.size {
+        let column = line.chars().take_while(|c| c.is_whitespace()).count();
+        let code = line.split('#').next().unwrap_or_default().trim();
+        let reason = format!("position {} is not on a dial of {} positions", target, dial.size);
+        return Err(ParseError::new(Day01::DAY, n, column + 1, &code[1..], reason));
+    }
+    Ok(cmd)
+}
+
+/// Part 1: how many commands leave the dial at zero.
+pub fn landed_zeroes(dial: &Dial, cmds: &[Cmd]) -> u64 {
+    solve(dial, cmds).filter(|&p| p == 0).count() as u64
+}
+
+/// Part 2: how many clicks point the dial at zero.
+pub fn passed_zeroes(dial: &Dial, cmds: &[Cmd]) -> u64 {
+    solve2(dial, cmds).map(|(_, c)| c).sum()
+}
+
+pub fn solve<'a>(dial: &'a Dial, cmds: &'a [Cmd]) -> impl Iterator<Item=Position> + 'a {
+    cmds
+        .iter()
+        .scan(dial.start, |state, cmd| {
+            *state = cmd.apply(dial, state);
+            Some(*state)
+        })
+}
+
+pub fn solve2<'a>(dial: &'a Dial, cmds: &'a [Cmd]) -> impl Iterator<Item=(Position, u64)> + 'a {
+    cmds
+        .iter()
+        .scan((dial.start, 0), |(pos, _), cmd| {
+            let (new_pos, new_zeroes) = cmd.apply_zero_counting(dial, pos);
+            *pos = new_pos;
+            Some((
+                new_pos,
+                new_zeroes,
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("L10\nR20\nS30\n").unwrap(), vec![Cmd::Left(10), Cmd::Right(20), Cmd::Set(30)]);
+        assert_eq!(parse("# start\n\n  L10  # back\n\t\nR20").unwrap(), vec![Cmd::Left(10), Cmd::Right(20)]);
+        assert_eq!(parse("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("L10\nX30\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X30"));
+
+        let err = parse("L10\n  R  # no distance").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, ""));
+
+        let err = parse("R-5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "-5"));
+
+        let err = parse("L+5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "+5"));
+
+        let err = parse_on(&Dial::default(), "S99\n  S150 # off the dial\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "150"));
+        assert_eq!(parse("S150").unwrap(), vec![Cmd::Set(150)]);
+
+        assert!(parse("L 10").is_err());
+        assert!(parse("l10").is_err());
+    }
+
+    #[test]
+    fn test_set() {
+        let dial = Dial::default();
+        assert_eq!(Cmd::Set(0).apply_zero_counting(&dial, &50), (0, 1));
+        assert_eq!(Cmd::Set(0).apply_zero_counting(&dial, &0), (0, 0));
+        assert_eq!(Cmd::Set(120).apply_zero_counting(&dial, &50), (20, 0));
+        assert_eq!(Cmd::Set(7).clicks(&dial, &50).collect::<Vec<_>>(), vec![7]);
+        assert_eq!(Cmd::Set(50).clicks(&dial, &50).count(), 0);
+    }
+
+    #[test]
+    fn test_cmd_apply() {
+        let dial = Dial::default();
+        assert_eq!(Cmd::Left(10).apply(&dial, &50), 40);
+        assert_eq!(Cmd::Right(20).apply(&dial, &50), 70);
+        assert_eq!(Cmd::Left(60).apply(&dial, &50), 90);
+        assert_eq!(Cmd::Right(60).apply(&dial, &50), 10);
+
+
+        assert_eq!(Cmd::Left(68).apply(&dial, &50), 82);
+        assert_eq!(Cmd::Left(30).apply(&dial, &82), 52);
+        assert_eq!(Cmd::Right(52).apply(&dial, &48), 0);
+    }
+
+
+    #[test]
+    fn test_cmd_apply_zero_counting() {
+        let dial = Dial::default();
+        assert_eq!(Cmd::Left(68).apply_zero_counting(&dial, &50), (82, 1));
+        assert_eq!(Cmd::Right(60).apply_zero_counting(&dial, &95), (55, 1));
+        assert_eq!(Cmd::Left(82).apply_zero_counting(&dial, &14), (32, 1));
+
+        assert_eq!(Cmd::Right(1000).apply_zero_counting(&dial, &50), (50, 10));
+
+        // assert_eq!(Cmd::Right(52).apply(48), 0);
+
+        // 14 -  L82 -> 1
+        // 14 - L182 -> 2
+    }
+
+    #[test]
+    fn text_example() {
+        let input = include_str!("examples/demo.txt");
+        let result: Vec<Position> = solve(&Dial::default(), &parse(input).unwrap()).collect();
+        insta::assert_snapshot!(format!("{:?}", result), @"[82, 52, 0, 95, 55, 0, 99, 0, 14, 32]");
+    }
+
+    #[test]
+    fn test_dial() {
+        let dial = Dial::new(7, 3).unwrap();
+        assert_eq!(Cmd::Left(10).apply(&dial, &3), 0);
+        assert_eq!(Cmd::Left(10).apply_zero_counting(&dial, &3), (0, 2));
+        assert_eq!(Cmd::Right(4).apply_zero_counting(&dial, &3), (0, 1));
+        assert_eq!(Cmd::Left(7).apply_zero_counting(&dial, &0), (0, 1));
+
+        let dial = Dial::new(1_000_000, 0).unwrap();
+        let step = 5_000_000_000_000;
+        assert_eq!(Cmd::Right(step).apply_zero_counting(&dial, &999_999), (999_999, 5_000_000));
+        assert_eq!(Cmd::Left(step).apply_zero_counting(&dial, &1), (1, 5_000_000));
+
+        let dial = Dial::new(u64::MAX, 5).unwrap();
+        assert_eq!(Cmd::Left(1).apply_zero_counting(&dial, &5), (4, 0));
+        assert_eq!(Cmd::Left(6).apply_zero_counting(&dial, &5), (u64::MAX - 1, 1));
+        assert_eq!(Cmd::Right(u64::MAX - 1).apply_zero_counting(&dial, &5), (4, 1));
+        assert_eq!(Cmd::Set(3).clicks(&dial, &(u64::MAX - 2)).collect::<Vec<_>>(), vec![3]);
+        assert_eq!(Cmd::Left(2).clicks(&dial, &0).collect::<Vec<_>>(), vec![u64::MAX - 1, u64::MAX - 2]);
+
+        assert!(Dial::new(0, 0).is_err());
+        assert!(Dial::new(100, 100).is_err());
+    }
+
+    #[test]
+    fn test_simulate() {
+        let dial = Dial::default();
+        assert_eq!(Cmd::Left(68).simulate(&dial, &50), (82, 1));
+        assert_eq!(Cmd::Right(1000).simulate(&dial, &50), (50, 10));
+        assert_eq!(Cmd::Left(0).simulate(&dial, &0), (0, 0));
+        assert_eq!(Cmd::Right(3).clicks(&dial, &98).collect::<Vec<_>>(), vec![99, 0, 1]);
+    }
+
+    #[test]
+    fn test_zero_counting_matches_simulation() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..20_000 {
+            let dial = Dial::new(rng.gen_range(1..=200), 0).unwrap();
+            let state = rng.gen_range(0..dial.size);
+            let cmd = random_cmd(&mut rng, 5 * dial.size);
+
+            assert_eq!(
+                cmd.apply_zero_counting(&dial, &state),
+                cmd.simulate(&dial, &state),
+                "{:?} from {} on a dial of {}", cmd, state, dial.size,
+            );
+        }
+    }
+
+    #[test]
+    fn test_programs_match_simulation() {
+        let mut rng = StdRng::seed_from_u64(2);
+
+        for _ in 0..500 {
+            let (dial, cmds) = random_program(&mut rng, 40, 3);
+
+            let simulated: Vec<_> = cmds.iter()
+                .scan(dial.start, |pos, cmd| {
+                    let (new_pos, zeroes) = cmd.simulate(&dial, pos);
+                    *pos = new_pos;
+                    Some((new_pos, zeroes))
+                })
+                .collect();
+
+            assert_eq!(solve2(&dial, &cmds).collect::<Vec<_>>(), simulated, "{:?} on {:?}", cmds, dial);
+            assert_eq!(
+                solve(&dial, &cmds).collect::<Vec<_>>(),
+                simulated.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+            );
+        }
+    }
+}
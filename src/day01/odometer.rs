@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use super::{Cmd, Dial, Position};
+
+/// When a wheel moves the next one, always by one step per carry.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Carry {
+    /// Every click pointing at zero, turning the next wheel the same way.
+    Zero,
+    /// Every click across the `size - 1`/`0` boundary, the same way, like a car odometer.
+    Wrap,
+    /// Only a move that ends at zero, turning the next wheel the same way.
+    Landed,
+}
+
+impl FromStr for Carry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zero" => Ok(Carry::Zero),
+            "wrap" => Ok(Carry::Wrap),
+            "landed" => Ok(Carry::Landed),
+            _ => Err(format!("unknown carry rule {:?}, expected zero, wrap or landed", s)),
+        }
+    }
+}
+
+/// One dial of the stack and how it carries into the next.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Wheel {
+    pub dial: Dial,
+    pub carry: Carry,
+}
+
+/// A stack of dials where commands turn the first and carries ripple upwards.
+#[derive(Debug)]
+pub struct Odometer {
+    pub wheels: Vec<Wheel>,
+    pub positions: Vec<Position>,
+    /// Clicks each wheel pointed at zero, counted as in part 2.
+    pub zeroes: Vec<u64>,
+}
+
+impl Odometer {
+    pub fn new(wheels: Vec<Wheel>) -> Odometer {
+        let positions = wheels.iter().map(|w| w.dial.start).collect();
+        let zeroes = vec![0; wheels.len()];
+        Odometer { wheels, positions, zeroes }
+    }
+
+    pub fn push(&mut self, cmd: &Cmd) {
+        let mut cmd = *cmd;
+
+        for (i, wheel) in self.wheels.iter().enumerate() {
+            let state = self.positions[i];
+            let (pos, zeroes) = cmd.apply_zero_counting(&wheel.dial, &state);
+            self.positions[i] = pos;
+            self.zeroes[i] += zeroes;
+
+            let carried = match wheel.carry {
+                Carry::Zero => zeroes,
+                Carry::Wrap => wraps(&cmd, &wheel.dial, state),
+                Carry::Landed => (pos == 0) as u64,
+            };
+            if carried == 0 {
+                break;
+            }
+            cmd = match cmd {
+                Cmd::Left(_) => Cmd::Left(carried),
+                Cmd::Right(_) | Cmd::Set(_) => Cmd::Right(carried),
+            };
+        }
+    }
+}
+
+/// Clicks of `cmd` from `state` going from `size - 1` to `0` or back.
+fn wraps(cmd: &Cmd, dial: &Dial, state: Position) -> u64 {
+    match *cmd {
+        Cmd::Right(step) => ((state as u128 + step as u128) / dial.size as u128) as u64,
+        // the clicks leaving zero, the first after `state` clicks
+        Cmd::Left(step) if step > state => (step - state - 1) / dial.size + 1,
+        Cmd::Left(_) | Cmd::Set(_) => 0,
+    }
+}
+
+impl Display for Odometer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:>5}  {:>10}  {:>10}  {:>10}", "wheel", "size", "position", "zeroes")?;
+        for (i, wheel) in self.wheels.iter().enumerate() {
+            writeln!(f, "{:>5}  {:>10}  {:>10}  {:>10}", i, wheel.dial.size, self.positions[i], self.zeroes[i])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::{parse, passed_zeroes};
+    use super::*;
+
+    fn decimal(carry: Carry) -> Odometer {
+        let wheel = Wheel { dial: Dial::new(10, 0).unwrap(), carry };
+        Odometer::new(vec![wheel; 3])
+    }
+
+    fn reading(odometer: &Odometer) -> u64 {
+        odometer.positions.iter().rev().fold(0, |acc, p| acc * 10 + p)
+    }
+
+    #[test]
+    fn test_wrap_counts() {
+        let mut odometer = decimal(Carry::Wrap);
+
+        odometer.push(&Cmd::Right(123));
+        assert_eq!(reading(&odometer), 123);
+        odometer.push(&Cmd::Left(4));
+        assert_eq!(reading(&odometer), 119);
+        odometer.push(&Cmd::Left(119));
+        assert_eq!(reading(&odometer), 0);
+        odometer.push(&Cmd::Left(1));
+        assert_eq!(reading(&odometer), 999);
+        odometer.push(&Cmd::Right(1001));
+        assert_eq!(reading(&odometer), 0);
+    }
+
+    #[test]
+    fn test_zero_carry() {
+        let mut odometer = decimal(Carry::Zero);
+
+        odometer.push(&Cmd::Right(123));
+        assert_eq!(odometer.positions, vec![3, 2, 1]);
+        // landing on zero going left already carries
+        odometer.push(&Cmd::Left(3));
+        assert_eq!(odometer.positions, vec![0, 1, 1]);
+        assert_eq!(odometer.zeroes, vec![13, 1, 0]);
+    }
+
+    #[test]
+    fn test_landed_carry() {
+        let mut odometer = decimal(Carry::Landed);
+
+        odometer.push(&Cmd::Right(25));
+        assert_eq!(odometer.positions, vec![5, 0, 0]);
+        odometer.push(&Cmd::Right(5));
+        assert_eq!(odometer.positions, vec![0, 1, 0]);
+        odometer.push(&Cmd::Set(0));
+        assert_eq!(odometer.positions, vec![0, 2, 0]);
+    }
+
+    #[test]
+    fn test_first_wheel_is_part2() {
+        let dial = Dial::default();
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+        let mut odometer = Odometer::new(vec![
+            Wheel { dial, carry: Carry::Zero },
+            Wheel { dial: Dial::new(4, 0).unwrap(), carry: Carry::Zero },
+        ]);
+        cmds.iter().for_each(|cmd| odometer.push(cmd));
+
+        assert_eq!(odometer.zeroes[0], passed_zeroes(&dial, &cmds));
+        assert_eq!("x".parse::<Carry>(), Err("unknown carry rule \"x\", expected zero, wrap or landed".to_string()));
+    }
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+use super::{turn, Cmd, Dial, Position};
+
+/// Which way each command of a plan may turn.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Turns {
+    /// Whichever way takes fewer clicks, right on a tie.
+    Shortest,
+    /// Right, then left, then right again, like opening a combination lock.
+    RightFirst,
+    /// Left, then right, then left again.
+    LeftFirst,
+}
+
+impl FromStr for Turns {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "shortest" => Ok(Turns::Shortest),
+            "rl" => Ok(Turns::RightFirst),
+            "lr" => Ok(Turns::LeftFirst),
+            _ => Err(format!("unknown turns {:?}, expected shortest, rl or lr", s)),
+        }
+    }
+}
+
+/// One command per target, turning the fewest clicks `turns` allows, so
+/// [`super::solve`] on the plan yields exactly `targets`.
+pub fn plan(dial: &Dial, targets: &[Position], turns: Turns) -> anyhow::Result<Vec<Cmd>> {
+    let mut pos = dial.start;
+    let mut cmds = Vec::with_capacity(targets.len());
+
+    for (i, &target) in targets.iter().enumerate() {
+        if target >= dial.size {
+            anyhow::bail!("target {} is not on a dial of {} positions", target, dial.size);
+        }
+
+        let right = turn(target, dial.size - pos, dial.size);
+        let left = turn(pos, dial.size - target, dial.size);
+        cmds.push(match turns {
+            Turns::Shortest if left < right => Cmd::Left(left),
+            Turns::Shortest => Cmd::Right(right),
+            Turns::RightFirst | Turns::LeftFirst if (i % 2 == 0) == (turns == Turns::RightFirst) => Cmd::Right(right),
+            Turns::RightFirst | Turns::LeftFirst => Cmd::Left(left),
+        });
+        pos = target;
+    }
+
+    Ok(cmds)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::day01::{parse, random_program, solve};
+    use super::*;
+
+    fn clicks(cmds: &[Cmd]) -> u64 {
+        cmds.iter().map(|cmd| match *cmd {
+            Cmd::Left(step) | Cmd::Right(step) => step,
+            Cmd::Set(_) => unreachable!(),
+        }).sum()
+    }
+
+    #[test]
+    fn test_demo() {
+        let dial = Dial::default();
+        let demo = parse(include_str!("examples/demo.txt")).unwrap();
+        let targets: Vec<_> = solve(&dial, &demo).collect();
+        let cmds = plan(&dial, &targets, Turns::Shortest).unwrap();
+
+        let text: String = cmds.iter().map(|cmd| format!("{}\n", cmd)).collect();
+        insta::assert_snapshot!(text.replace('\n', " "), @"R32 L30 R48 L5 L40 R45 L1 R1 R14 R18 ");
+        assert_eq!(solve(&dial, &parse(&text).unwrap()).collect::<Vec<_>>(), targets);
+        assert!(clicks(&cmds) < clicks(&demo));
+    }
+
+    #[test]
+    fn test_alternating() {
+        let dial = Dial::default();
+
+        assert_eq!(plan(&dial, &[40, 60, 60], Turns::RightFirst).unwrap(), vec![Cmd::Right(90), Cmd::Left(80), Cmd::Right(0)]);
+        assert_eq!(plan(&dial, &[40, 60, 60], Turns::LeftFirst).unwrap(), vec![Cmd::Left(10), Cmd::Right(20), Cmd::Left(0)]);
+        assert!(plan(&dial, &[100], Turns::Shortest).is_err());
+        assert_eq!("rl".parse::<Turns>(), Ok(Turns::RightFirst));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = StdRng::seed_from_u64(21);
+
+        for _ in 0..200 {
+            // any program's positions make a valid combination
+            let (dial, program) = random_program(&mut rng, 20, 2);
+            let targets: Vec<_> = solve(&dial, &program).collect();
+
+            for turns in [Turns::Shortest, Turns::RightFirst, Turns::LeftFirst] {
+                let cmds = plan(&dial, &targets, turns).unwrap();
+                let text: String = cmds.iter().map(|cmd| format!("{}\n", cmd)).collect();
+                assert_eq!(solve(&dial, &parse(&text).unwrap()).collect::<Vec<_>>(), targets);
+                assert!(cmds.iter().all(|cmd| matches!(cmd, Cmd::Left(s) | Cmd::Right(s) if *s < dial.size)));
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::f64::consts::TAU;
+use std::iter::once;
+use super::{solve, Cmd, Dial, Position};
+
+/// The dial after one click of an animation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Frame {
+    /// 0-based index of the command turning the dial.
+    pub index: usize,
+    pub cmd: Cmd,
+    pub pos: Position,
+    /// Clicks so far that pointed at zero, this one included.
+    pub zeroes: u64,
+}
+
+/// One frame per click of every command; commands that do not move the dial have none.
+pub fn frames<'a>(dial: &'a Dial, cmds: &'a [Cmd]) -> impl Iterator<Item=Frame> + 'a {
+    let starts = once(dial.start).chain(solve(dial, cmds));
+
+    cmds.iter()
+        .zip(starts)
+        .enumerate()
+        .flat_map(|(index, (&cmd, start))| cmd.clicks(dial, &start).map(move |pos| (index, cmd, pos)))
+        .scan(0, |zeroes, (index, cmd, pos)| {
+            *zeroes += (pos == 0) as u64;
+            Some(Frame { index, cmd, pos, zeroes: *zeroes })
+        })
+}
+
+/// Draws the dial as a ring of ticks, `radius` rows from the centre, zero at
+/// the top and positions going clockwise. The pointer is `@`, or `*` while it
+/// is on zero. Large dials get evenly spaced ticks, a couple per column.
+pub fn draw(dial: &Dial, frame: &Frame, radius: usize) -> String {
+    let (rows, cols) = (2 * radius + 1, 4 * radius + 1);
+    let mut grid = vec![vec![' '; cols]; rows];
+
+    let mut put = |pos: Position, c: char| {
+        let angle = TAU * pos as f64 / dial.size as f64;
+        let row = (radius as f64 * (1.0 - angle.cos())).round() as usize;
+        // characters are about twice as high as wide
+        let col = (radius as f64 * (2.0 + 2.0 * angle.sin())).round() as usize;
+        grid[row][col] = c;
+    };
+    let ticks = dial.size.min(2 * cols as u64);
+    (1..ticks).for_each(|tick| put((tick as u128 * dial.size as u128 / ticks as u128) as Position, '.'));
+    put(0, '0');
+    put(frame.pos, if frame.pos == 0 { '*' } else { '@' });
+
+    let mut out: String = grid
+        .iter()
+        .map(|row| row.iter().collect::<String>().trim_end().to_string() + "\n")
+        .collect();
+    out.push_str(&format!(
+        "command {} {}  position {}  zeroes {}{}\n",
+        frame.index + 1, frame.cmd, frame.pos, frame.zeroes,
+        if frame.pos == 0 { "  ZERO" } else { "" },
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::{parse, passed_zeroes};
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let dial = Dial::default();
+        let cmds = parse("L68\nR0\nR48\nS0\nS5").unwrap();
+        let frames: Vec<_> = frames(&dial, &cmds).collect();
+
+        assert_eq!(frames.len(), 68 + 48 + 1 + 1);
+        assert_eq!(frames[0], Frame { index: 0, cmd: Cmd::Left(68), pos: 49, zeroes: 0 });
+        assert_eq!(frames[68].index, 2);
+        assert_eq!(frames.last().unwrap().zeroes, passed_zeroes(&dial, &cmds));
+    }
+
+    #[test]
+    fn test_draw() {
+        let dial = Dial::new(8, 0).unwrap();
+        let frame = Frame { index: 0, cmd: Cmd::Right(3), pos: 3, zeroes: 0 };
+
+        insta::assert_snapshot!(draw(&dial, &frame, 3), @r"
+              0
+          .       .
+
+        .           .
+
+          .       @
+              .
+        command 1 R3  position 3  zeroes 0
+        ");
+
+        let frame = Frame { index: 1, cmd: Cmd::Left(3), pos: 0, zeroes: 1 };
+        assert!(draw(&dial, &frame, 3).starts_with("      *\n"));
+        assert!(draw(&dial, &frame, 3).ends_with("zeroes 1  ZERO\n"));
+
+        // sampled rather than a tick per position
+        let dial = Dial::new(u64::MAX, 0).unwrap();
+        let frame = Frame { index: 0, cmd: Cmd::Left(1), pos: u64::MAX - 1, zeroes: 0 };
+        assert_eq!(draw(&dial, &frame, 3).lines().count(), 8);
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use super::summary::Summary;
+use super::{Cmd, Dial, Position};
+
+/// Both answers for every position the dial could start at.
+#[derive(Debug)]
+pub struct Sensitivity {
+    /// Part 1 answer, by start position.
+    pub part1: Vec<u64>,
+    /// Part 2 answer, by start position.
+    pub part2: Vec<u64>,
+}
+
+/// Best, worst and the distribution of one part's answers over all starts.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Spread {
+    /// Highest answer and the first start reaching it.
+    pub best: (Position, u64),
+    /// Lowest answer and the first start reaching it.
+    pub worst: (Position, u64),
+    /// Number of starts giving each answer.
+    pub distribution: BTreeMap<u64, u64>,
+}
+
+impl Sensitivity {
+    /// A single [`Summary`] of the whole list already holds the answers per entry position;
+    /// fails for dials above [`super::MAX_TABLE`] positions.
+    pub fn new(dial: &Dial, cmds: &[Cmd]) -> anyhow::Result<Sensitivity> {
+        let chunk = cmds.len() / rayon::current_num_threads() + 1;
+        let summary = Summary::parallel(dial, cmds, chunk)?;
+        Ok(Sensitivity { part1: summary.landed, part2: summary.passed })
+    }
+}
+
+impl Spread {
+    pub fn new(answers: &[u64]) -> Spread {
+        let mut spread = Spread { best: (0, answers[0]), worst: (0, answers[0]), distribution: BTreeMap::new() };
+
+        for (start, &answer) in answers.iter().enumerate() {
+            if answer > spread.best.1 {
+                spread.best = (start as Position, answer);
+            }
+            if answer < spread.worst.1 {
+                spread.worst = (start as Position, answer);
+            }
+            *spread.distribution.entry(answer).or_default() += 1;
+        }
+
+        spread
+    }
+}
+
+impl Display for Sensitivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (part, answers) in [(1, &self.part1), (2, &self.part2)] {
+            let spread = Spread::new(answers);
+            writeln!(f, "part {}: best {} from {}, worst {} from {}",
+                part, spread.best.1, spread.best.0, spread.worst.1, spread.worst.0)?;
+            writeln!(f, "{:>10}  {:>8}", "answer", "starts")?;
+            for (answer, starts) in &spread.distribution {
+                writeln!(f, "{:>10}  {:>8}", answer, starts)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::{landed_zeroes, parse, passed_zeroes};
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+        let sensitivity = Sensitivity::new(&Dial::default(), &cmds).unwrap();
+
+        for start in 0..100 {
+            let dial = Dial::new(100, start).unwrap();
+            assert_eq!(sensitivity.part1[start as usize], landed_zeroes(&dial, &cmds));
+            assert_eq!(sensitivity.part2[start as usize], passed_zeroes(&dial, &cmds));
+        }
+        assert_eq!(sensitivity.part1[50], 3);
+        assert_eq!(sensitivity.part2[50], 6);
+        assert!(Sensitivity::new(&Dial::new(u64::MAX, 0).unwrap(), &cmds).is_err());
+    }
+
+    #[test]
+    fn test_spread() {
+        let spread = Spread::new(&[2, 5, 1, 5, 2, 1]);
+
+        assert_eq!(spread.best, (1, 5));
+        assert_eq!(spread.worst, (2, 1));
+        assert_eq!(spread.distribution, BTreeMap::from([(1, 2), (2, 2), (5, 2)]));
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::{BufRead, Write};
+use super::{parse_line_on, Cmd, Dial, Position};
+
+/// Position and running answers of a dial fed one command at a time.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Tracker {
+    pub dial: Dial,
+    pub pos: Position,
+    /// Commands so far that ended at zero, the part 1 answer.
+    pub landed: u64,
+    /// Clicks so far that pointed at zero, the part 2 answer.
+    pub passed: u64,
+}
+
+impl Tracker {
+    pub fn new(dial: Dial) -> Tracker {
+        Tracker { dial, pos: dial.start, landed: 0, passed: 0 }
+    }
+
+    pub fn push(&mut self, cmd: &Cmd) {
+        let (pos, zeroes) = cmd.apply_zero_counting(&self.dial, &self.pos);
+        self.pos = pos;
+        self.landed += (pos == 0) as u64;
+        self.passed += zeroes;
+    }
+}
+
+/// Reads commands from `input` as they arrive and writes `position landed passed`
+/// to `output` after each, flushing so a live feed shows up right away.
+pub fn stream(dial: Dial, input: impl BufRead, mut output: impl Write) -> anyhow::Result<Tracker> {
+    let mut tracker = Tracker::new(dial);
+
+    for (n, line) in input.lines().enumerate() {
+        if let Some(cmd) = parse_line_on(&dial, n, &line?)? {
+            tracker.push(&cmd);
+            writeln!(output, "{} {} {}", tracker.pos, tracker.landed, tracker.passed)?;
+            output.flush()?;
+        }
+    }
+
+    Ok(tracker)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day01::{landed_zeroes, parse, passed_zeroes};
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let input = include_str!("examples/demo.txt");
+        let dial = Dial::default();
+        let mut output = Vec::new();
+        let tracker = stream(dial, input.as_bytes(), &mut output).unwrap();
+
+        let cmds = parse(input).unwrap();
+        assert_eq!((tracker.landed, tracker.passed), (landed_zeroes(&dial, &cmds), passed_zeroes(&dial, &cmds)));
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), cmds.len());
+        assert_eq!(output.lines().next(), Some("82 0 1"));
+        assert_eq!(output.lines().last(), Some("32 3 6"));
+    }
+
+    #[test]
+    fn test_error_keeps_output() {
+        let mut output = Vec::new();
+        let err = stream(Dial::default(), "R50\n# ok\nX1\nL1\n".as_bytes(), &mut output).unwrap_err();
+
+        assert!(err.to_string().contains("line 3"), "{}", err);
+        assert_eq!(output, b"0 1 1\n");
+
+        let err = stream(Dial::default(), "S150\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("not on a dial"), "{}", err);
+    }
+}
@@ -0,0 +1,141 @@
+use rayon::prelude::*;
+use super::{table_size, Cmd, Dial, Position};
+
+/// What a contiguous run of commands does to the dial, for every position it
+/// may be entered at, so runs can be evaluated apart and merged afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// Where the run leaves the dial.
+    pub exit: Exit,
+    /// Commands of the run ending at zero, by entry position.
+    pub landed: Vec<u64>,
+    /// Clicks of the run pointing at zero, by entry position.
+    pub passed: Vec<u64>,
+}
+
+/// Where a run leaves the dial, relative to where it was entered or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// Rotation to the right over the whole run, modulo the dial size.
+    Turn(u64),
+    /// The position of the run's last [`Cmd::Set`], turned by what follows it.
+    Fixed(Position),
+}
+
+impl Summary {
+    /// The empty run; fails for dials above [`super::MAX_TABLE`] positions, as
+    /// does every summary.
+    pub fn identity(dial: &Dial) -> anyhow::Result<Summary> {
+        let size = table_size(dial)?;
+        Ok(Summary { exit: Exit::Turn(0), landed: vec![0; size], passed: vec![0; size] })
+    }
+
+    pub fn of(dial: &Dial, cmds: &[Cmd]) -> anyhow::Result<Summary> {
+        let mut summary = Summary::identity(dial)?;
+
+        for entry in 0..dial.size {
+            let mut pos = entry;
+            for cmd in cmds {
+                let (new_pos, zeroes) = cmd.apply_zero_counting(dial, &pos);
+                pos = new_pos;
+                summary.landed[entry as usize] += (pos == 0) as u64;
+                summary.passed[entry as usize] += zeroes;
+            }
+            if entry == 0 {
+                let fixed = cmds.iter().any(|cmd| matches!(cmd, Cmd::Set(_)));
+                summary.exit = if fixed { Exit::Fixed(pos) } else { Exit::Turn(pos) };
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Summarises `cmds` in chunks of `chunk` commands on all cores.
+    pub fn parallel(dial: &Dial, cmds: &[Cmd], chunk: usize) -> anyhow::Result<Summary> {
+        let identity = Summary::identity(dial)?;
+        cmds
+            .par_chunks(chunk.max(1))
+            .map(|c| Summary::of(dial, c))
+            .try_reduce(|| identity.clone(), |a, b| Ok(a.combine(&b)))
+    }
+
+    fn size(&self) -> u64 {
+        self.landed.len() as u64
+    }
+
+    /// `self` followed by `next`; associative, with [`Summary::identity`] as neutral element.
+    pub fn combine(&self, next: &Summary) -> Summary {
+        let size = self.size();
+        let after = |entry: usize| self.exit(entry as Position) as usize;
+        let exit = match (self.exit, next.exit) {
+            (_, Exit::Fixed(pos)) => Exit::Fixed(pos),
+            (Exit::Turn(a), Exit::Turn(b)) => Exit::Turn((a + b) % size),
+            (Exit::Fixed(pos), Exit::Turn(b)) => Exit::Fixed((pos + b) % size),
+        };
+
+        Summary {
+            exit,
+            landed: self.landed.iter().enumerate().map(|(e, l)| l + next.landed[after(e)]).collect(),
+            passed: self.passed.iter().enumerate().map(|(e, p)| p + next.passed[after(e)]).collect(),
+        }
+    }
+
+    /// Position after the run when entered at `entry`.
+    pub fn exit(&self, entry: Position) -> Position {
+        match self.exit {
+            Exit::Turn(net) => (entry + net) % self.size(),
+            Exit::Fixed(pos) => pos,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use crate::day01::{landed_zeroes, parse, passed_zeroes, random_cmd, random_program, solve};
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let dial = Dial::default();
+        let cmds = parse(include_str!("examples/demo.txt")).unwrap();
+        let summary = Summary::parallel(&dial, &cmds, 3).unwrap();
+
+        assert_eq!(summary.landed[dial.start as usize], landed_zeroes(&dial, &cmds));
+        assert_eq!(summary.passed[dial.start as usize], passed_zeroes(&dial, &cmds));
+        assert_eq!(Some(summary.exit(dial.start)), solve(&dial, &cmds).last());
+        assert!(Summary::parallel(&Dial::new(1 << 40, 0).unwrap(), &cmds, 3).is_err());
+    }
+
+    #[test]
+    fn test_combine_associative() {
+        let mut rng = StdRng::seed_from_u64(14);
+
+        for _ in 0..200 {
+            let dial = Dial::new(rng.gen_range(1..=40), 0).unwrap();
+            let [a, b, c] = [(); 3].map(|_| {
+                let cmds: Vec<_> = (0..rng.gen_range(0..6)).map(|_| random_cmd(&mut rng, 3 * dial.size)).collect();
+                Summary::of(&dial, &cmds).unwrap()
+            });
+
+            assert_eq!(a.combine(&b).combine(&c), a.combine(&b.combine(&c)));
+            let identity = Summary::identity(&dial).unwrap();
+            assert_eq!(a.combine(&identity), a);
+            assert_eq!(identity.combine(&a), a);
+        }
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(41);
+
+        for _ in 0..50 {
+            let (dial, cmds) = random_program(&mut rng, 200, 3);
+            let summary = Summary::parallel(&dial, &cmds, rng.gen_range(1..20)).unwrap();
+
+            assert_eq!(summary, Summary::of(&dial, &cmds).unwrap());
+            assert_eq!(summary.passed[dial.start as usize], passed_zeroes(&dial, &cmds));
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use prime_factorization::Factorization;
+use itertools::Itertools;
+use log::trace;
+use num::{range_inclusive, BigInt, BigUint, Integer, Zero};
+use crate::error::number;
+use crate::{ParseError, Solution};
+use rule::Rule;
+
+pub mod rule;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Range(u128, u128);
+
+/// How many invalid IDs a range holds and what they add up to, exactly even
+/// for ranges of 128 bit IDs.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Tally {
+    pub count: BigUint,
+    pub sum: BigUint,
+}
+
+impl Range {
+    /// Sum of the IDs in the range that are invalid under `rule`.
+    pub fn sum_invalid(&self, rule: Rule) -> BigUint {
+        self.tally(rule).sum
+    }
+
+    /// Invalid IDs are a block of digits repeated, so per digit length `len`
+    /// they are counted and summed as block × multiplier for each block
+    /// length, without visiting the range.
+    ///
+    /// An ID made of blocks of `p` digits is also made of blocks of any
+    /// multiple of `p`, so each is counted once, under its shortest block `q`:
+    /// it is invalid when `q` divides a block length `rule` allows. The IDs
+    /// whose shortest block is `q` come out of those made of blocks of the
+    /// divisors of `q` by Möbius inversion.
+    pub fn tally(&self, rule: Rule) -> Tally {
+        let Range(start, end) = *self;
+        let (mut count, mut sum) = (BigInt::zero(), BigInt::zero());
+
+        for len in digits(start)..=digits(end) {
+            let lo = BigUint::from(start).max(pow10(len - 1));
+            let hi = BigUint::from(end).min(pow10(len) - 1u8);
+
+            let divisors: Vec<u32> = (1..len).filter(|d| len % d == 0).collect();
+            let allowed: Vec<u32> = divisors.iter().copied().filter(|&p| rule.allows(len / p, p)).collect();
+            let shortest: Vec<u32> = divisors.iter().copied().filter(|&q| allowed.iter().any(|p| p % q == 0)).collect();
+
+            for &period in &divisors {
+                let sign: i128 = shortest.iter().filter(|&q| q % period == 0).map(|q| mobius(q / period)).sum();
+                if sign != 0 {
+                    let (c, s) = periodic(&lo, &hi, len, period);
+                    count += sign * BigInt::from(c);
+                    sum += sign * BigInt::from(s);
+                }
+            }
+        }
+
+        Tally {
+            count: count.to_biguint().expect("inclusion-exclusion counts are never negative"),
+            sum: sum.to_biguint().expect("inclusion-exclusion sums are never negative"),
+        }
+    }
+
+    /// [`Range::tally`]'s sum the slow way, value by value.
+    pub fn scan_invalid(&self, rule: Rule) -> BigUint {
+        let Range(start, end) = *self;
+
+        range_inclusive(start, end).filter(|&v| {
+            let (len, splits) = all_splits(v);
+            if splits.is_empty() {
+                // single digit
+                return false
+            }
+
+            splits.into_iter().any(|part_len| {
+                if !rule.allows((len / part_len) as u32, part_len as u32) {
+                    return false
+                }
+
+                let uniq_parts = (0..(len / part_len))
+                    .map(
+                        |s_idx|
+                            slice_int(
+                                v,
+                                ((part_len * s_idx), ((s_idx + 1) * part_len))
+                            )
+                    )
+                    .counts().keys().count();
+
+                uniq_parts == 1
+            })
+        }).map(BigUint::from).sum()
+    }
+}
+
+impl Range {
+    /// `value` was found at 0-based `line` and `column` of the input.
+    fn parse(line: usize, column: usize, value: &str) -> Result<Self, ParseError> {
+        if let Some((l, r)) = value.split_once("-") {
+            Ok(Range(
+                number(Day02::DAY, line, column, l)?,
+                number(Day02::DAY, line, column + l.len() + 1, r)?,
+            ))
+        } else {
+            Err(ParseError::new(Day02::DAY, line, column, value, "expected `start-end`"))
+        }
+    }
+}
+
+
+/// Count and sum of the `len`-digit numbers in `lo..=hi` made of one block of
+/// `period` digits repeated.
+fn periodic(lo: &BigUint, hi: &BigUint, len: u32, period: u32) -> (BigUint, BigUint) {
+    // 1, 10^period, 10^2·period, ... added up
+    let multiplier = (pow10(len) - 1u8) / (pow10(period) - 1u8);
+    let first = lo.div_ceil(&multiplier).max(pow10(period - 1));
+    let last = (hi / &multiplier).min(pow10(period) - 1u8);
+
+    if first > last {
+        return (BigUint::zero(), BigUint::zero());
+    }
+    let n = &last - &first + 1u8;
+    let sum = multiplier * (first + last) * &n / 2u8;
+    (n, sum)
+}
+
+fn pow10(exp: u32) -> BigUint {
+    BigUint::from(10u8).pow(exp)
+}
+
+fn mobius(n: u32) -> i128 {
+    let factors = Factorization::run(n).factors;
+    match (factors.iter().all_unique(), factors.len() % 2) {
+        (false, _) => 0,
+        (true, 0) => 1,
+        (true, _) => -1,
+    }
+}
+
+/// Exact number of decimal digits, 1 for 0.
+fn digits(v: u128) -> u32 {
+    v.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn slice_int(v: u128, (b, t): (u64, u64)) -> u128 {
+
+    // v.mod_floor(&10u64.pow(t)) / 10u64.pow(b)
+
+    let bottom = v / 10u128.pow(b as u32);
+    let res = bottom.mod_floor(&10u128.pow((t - b) as u32));
+    trace!("slicing {}[{}:{}] -> {}", v, b, t, res);
+    res
+}
+
+fn all_splits(input: u128) -> (u64, Vec<u64>) {
+    let length = digits(input) as u64;
+
+    let factors = Factorization::run(length).factors;
+
+    (
+        length,
+        (1..=factors.len())
+            .flat_map(
+                |k| factors
+                    .iter()
+                    .permutations(k)
+                    .map(|v| v.into_iter().product::<u64>())
+            )
+            .chain(vec![1])
+            .unique()
+            .filter(|&v| v != length)
+            .sorted()
+            .collect()
+    )
+}
+
+/// Ranges are separated by commas, possibly wrapped over several lines.
+fn to_ranges(input: &str) -> Result<Vec<Range>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(n, line)| {
+            let mut column = 0;
+            line.split(",").filter_map(move |r| {
+                let indent = r.len() - r.trim_start().len();
+                let at = column + indent;
+                column += r.len() + 1;
+                (!r.trim().is_empty()).then(|| Range::parse(n, at, r.trim()))
+            })
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Range>;
+    type Answer = BigUint;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(to_ranges(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        tally(input, Rule::TWICE).sum
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        tally(input, Rule::REPEATED).sum
+    }
+}
+
+/// The invalid IDs of all `ranges` under `rule`.
+pub fn tally(ranges: &[Range], rule: Rule) -> Tally {
+    ranges.iter().map(|r| r.tally(rule)).fold(Tally::default(), |acc, t| Tally {
+        count: acc.count + t.count,
+        sum: acc.sum + t.sum,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use rstest::rstest;
+    use rule::Repeats;
+    use super::*;
+
+    impl Range {
+        fn scan(&self, rule: Rule) -> Tally {
+            let invalid: Vec<_> = (self.0..=self.1)
+                .filter(|&v| !Range(v, v).scan_invalid(rule).is_zero())
+                .collect();
+            Tally { count: BigUint::from(invalid.len()), sum: invalid.into_iter().map(BigUint::from).sum() }
+        }
+    }
+
+    fn big(v: u128) -> BigUint {
+        BigUint::from(v)
+    }
+
+    fn rules() -> Vec<Rule> {
+        let repeats = [
+            Repeats::Exactly(2), Repeats::Exactly(3), Repeats::AtLeast(2), Repeats::AtLeast(3),
+            Repeats::AtMost(2), Repeats::AtMost(3), Repeats::Prime,
+        ];
+        repeats.into_iter()
+            .flat_map(|repeats| (1..=3).map(move |min_block| Rule { repeats, min_block }))
+            .collect()
+    }
+
+    const DEMO_INPUT: &str = include_str!("examples/demo.txt");
+
+    #[test]
+    fn text_parse_range() {
+
+        let result = to_ranges(DEMO_INPUT).unwrap();
+        insta::assert_snapshot!(format!("{:?}", result), @"[Range(11, 22), Range(95, 115), Range(998, 1012), Range(1188511880, 1188511890), Range(222220, 222224), Range(1698522, 1698528), Range(446443, 446449), Range(38593856, 38593862), Range(565653, 565659), Range(824824821, 824824827), Range(2121212118, 2121212124)]");
+    }
+
+    // https://insta.rs/docs/patterns/
+    macro_rules! set_snapshot_suffix {
+        ($($expr:expr),*) => {
+            let mut settings = insta::Settings::clone_current();
+            settings.set_snapshot_suffix(format!($($expr,)*));
+            let _guard = settings.bind_to_scope();
+        }
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(11)]
+    #[case(111)]
+    #[case(1111)]
+    #[case(11111)]
+    #[case(111111)]
+    #[case(1111111)]
+    #[case(11111111)]
+    #[case(111111111)]
+    #[case(1111111111)]
+    #[case(11111111111)]
+    #[case(111111111111)]
+    #[case(1111111111111)]
+    // has to be a better way to do this :-D
+    fn test_all_splits(#[case] input: u128) {
+        set_snapshot_suffix!("{}", input);
+        insta::assert_debug_snapshot!(format!("{:?}", all_splits(input).1));
+    }
+
+    #[rstest]
+    #[case(1, (0, 0))]
+    #[case(12, (1, 0))]
+    #[case(1234, (2, 1))]
+    #[case(1234, (3, 2))]
+    // #[case(123456, (0, 2))]
+    fn test_slice_int(#[case] v: u128, #[case] (t, b): (u64, u64)) {
+        set_snapshot_suffix!("{}[{}:{}]", v, t, b);
+        insta::assert_debug_snapshot!(slice_int(v, (b, t)));
+    }
+
+    #[test]
+    fn test_edge() {
+        assert_eq!(slice_int(123456, (0, 1)), 6);
+        assert_eq!(slice_int(123456, (0, 2)), 56);
+        assert_eq!(slice_int(123456, (1, 3)), 45);
+    }
+    #[test]
+    fn test_range() {
+        assert_eq!(Range(11, 22).sum_invalid(Rule::TWICE), big(33));
+        assert_eq!(Range(95, 115).sum_invalid(Rule::TWICE), big(99));
+        assert_eq!(Range(998, 1012).sum_invalid(Rule::TWICE), big(1010));
+        assert_eq!(Range(1188511880, 1188511890).sum_invalid(Rule::TWICE), big(1188511885));
+
+        assert_eq!(Range(95, 115).sum_invalid(Rule::REPEATED), big(99+111));
+        assert_eq!(Range(565653, 565659).sum_invalid(Rule::REPEATED), big(565656));
+        assert_eq!(Range(824824821, 824824827).sum_invalid(Rule::REPEATED), big(824824824));
+        assert_eq!(Range(2121212118, 2121212124).sum_invalid(Rule::REPEATED), big(2121212121));
+
+        assert_eq!(Range(11, 22).sum_invalid(Rule::REPEATED), big(33));
+        assert_eq!(Range(95, 115).sum_invalid(Rule::REPEATED), big(99+111));
+        assert_eq!(Range(998, 1012).sum_invalid(Rule::REPEATED), big(999+1010));
+        assert_eq!(Range(1188511880, 1188511890).sum_invalid(Rule::REPEATED), big(1188511885));
+        assert_eq!(Range(222220, 222224).sum_invalid(Rule::REPEATED), big(222222));
+
+    }
+    #[test]
+    fn test_demo() {
+        let demo = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124";
+        
+        assert_eq!(to_ranges(demo).unwrap().iter().map(|r| r.sum_invalid(Rule::TWICE)).sum::<BigUint>(), big(1227775554));
+    }
+    #[test]
+    fn test_demo_2() {
+        let demo = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124";
+
+        assert_eq!(to_ranges(demo).unwrap().iter().map(|r| r.sum_invalid(Rule::REPEATED)).sum::<BigUint>(), big(4174379265));
+    }
+    #[test]
+    fn test_tally_matches_scan() {
+        let mut rng = StdRng::seed_from_u64(23);
+
+        for _ in 0..60 {
+            let digits = rng.gen_range(1..8);
+            let start = rng.gen_range(1..10u128.pow(digits));
+            let range = Range(start, start + rng.gen_range(0..300));
+            for rule in rules() {
+                assert_eq!(range.tally(rule), range.scan(rule), "{:?} under {:?}", range, rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tally_wide() {
+        // 11, 22, ..., 99 and 1010, 1111, ..., 9999
+        assert_eq!(Range(1, 9999).tally(Rule::TWICE), Tally { count: big(9 + 90), sum: big(495 + 4905 * 101) });
+        // the same plus 111, ..., 999 (1111 is counted only once)
+        assert_eq!(Range(1, 9999).tally(Rule::REPEATED), Tally { count: big(9 + 9 + 90), sum: big(495 + 111 * 45 + 4905 * 101) });
+
+        assert_eq!(Range(1, 10u128.pow(12)).tally(Rule::TWICE).count, big(999_999));
+        // 11 digits only repeat a single one
+        assert_eq!(Range(10u128.pow(10), 10u128.pow(11) - 1).tally(Rule::REPEATED).count, big(9));
+        assert_eq!(Range(10u128.pow(10), 10u128.pow(11) - 1).tally(Rule::TWICE).count, big(0));
+    }
+
+    #[test]
+    fn test_rules() {
+        let count = |repeats, min_block| Range(1, 999_999).tally(Rule { repeats, min_block }).count;
+
+        // 111..999 and 101010..999999
+        assert_eq!(count(Repeats::Exactly(3), 1), big(9 + 90));
+        assert_eq!(count(Repeats::Exactly(3), 2), big(90));
+        // 11..99, 1010..9999 and 100100..999999
+        assert_eq!(count(Repeats::AtMost(2), 1), big(9 + 90 + 900));
+        // as many as with any repeats, 6 digits made of 2 or 3 alike with 111111 and so on in both
+        assert_eq!(count(Repeats::Prime, 1), big(9 + 9 + 90 + 9 + (900 + 90 - 9)));
+        assert_eq!(count(Repeats::Prime, 1), count(Repeats::AtLeast(2), 1));
+        assert_eq!(count(Repeats::AtLeast(4), 1), big(9 + 9 + 9));
+    }
+
+    #[test]
+    fn test_tally_u128() {
+        // every half split ID below 10^38 is a block of 1 to 19 digits twice
+        let half = Range(1, 10u128.pow(38) - 1).tally(Rule::TWICE);
+        assert_eq!(half.count, big(10u128.pow(19) - 1));
+        assert!(half.sum > big(u128::MAX));
+
+        // 39 digits: a block of 13 thrice or of 3 (up to 339) 13 times,
+        // where 111..1 to 333..3 are both
+        let top = Range(10u128.pow(38), u128::MAX).tally(Rule::REPEATED);
+        let thirteen = u128::MAX / (1 + 10u128.pow(13) + 10u128.pow(26)) - 10u128.pow(12) + 1;
+        assert_eq!(top.count, big(thirteen + (339 - 100 + 1) - 3));
+        assert_eq!(Range(u128::MAX, u128::MAX).tally(Rule::REPEATED), Tally::default());
+        assert!(to_ranges("1-340282366920938463463374607431768211456").is_err());
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(0), 1);
+        assert_eq!(digits(999_999_999_999_999_999), 18);
+        assert_eq!(digits(1_000_000_000_000_000_000), 19);
+        assert_eq!(digits(u128::MAX), 39);
+        assert_eq!(all_splits(10u128.pow(15) - 1).0, 15);
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(all_splits(100).0, 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = to_ranges("11-22,95-115,\n998-1012, 12x-13").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "12x"));
+
+        let err = to_ranges("11-22,95").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "95"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use prime_factorization::Factorization;
+
+/// How many times a block of digits has to repeat to make an ID invalid.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Repeats {
+    Exactly(u32),
+    AtLeast(u32),
+    AtMost(u32),
+    /// A prime number of times.
+    Prime,
+}
+
+/// Which repeated blocks make an ID invalid. An ID is invalid when it is a
+/// single block repeated a number of times `repeats` allows, the block having
+/// at least `min_block` digits.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Rule {
+    pub repeats: Repeats,
+    pub min_block: u32,
+}
+
+impl Rule {
+    /// Part 1: a block twice.
+    pub const TWICE: Rule = Rule { repeats: Repeats::Exactly(2), min_block: 1 };
+    /// Part 2: a block twice or more.
+    pub const REPEATED: Rule = Rule { repeats: Repeats::AtLeast(2), min_block: 1 };
+
+    /// Whether a block of `block` digits repeated `k` times counts; a block
+    /// on its own never does.
+    pub fn allows(&self, k: u32, block: u32) -> bool {
+        let repeats = match self.repeats {
+            Repeats::Exactly(n) => k == n,
+            Repeats::AtLeast(n) => k >= n,
+            Repeats::AtMost(n) => k <= n,
+            Repeats::Prime => Factorization::run(k).is_prime,
+        };
+        k >= 2 && repeats && block >= self.min_block
+    }
+}
+
+impl FromStr for Repeats {
+    type Err = String;
+
+    /// `exactly-K`, `at-least-K`, `at-most-K` or `prime`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "prime" {
+            return Ok(Repeats::Prime);
+        }
+
+        let (kind, k) = s
+            .rsplit_once('-')
+            .ok_or_else(|| format!("expected exactly-K, at-least-K, at-most-K or prime, got {:?}", s))?;
+        let k = k.parse().map_err(|err| format!("bad count in {:?}: {}", s, err))?;
+        match kind {
+            "exactly" => Ok(Repeats::Exactly(k)),
+            "at-least" => Ok(Repeats::AtLeast(k)),
+            "at-most" => Ok(Repeats::AtMost(k)),
+            _ => Err(format!("expected exactly-K, at-least-K, at-most-K or prime, got {:?}", s)),
+        }
+    }
+}
+
+impl Display for Repeats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Repeats::Exactly(k) => write!(f, "exactly-{}", k),
+            Repeats::AtLeast(k) => write!(f, "at-least-{}", k),
+            Repeats::AtMost(k) => write!(f, "at-most-{}", k),
+            Repeats::Prime => write!(f, "prime"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        assert!(Rule::TWICE.allows(2, 3));
+        assert!(!Rule::TWICE.allows(3, 2));
+        assert!(Rule::REPEATED.allows(5, 1));
+        assert!(!Rule::REPEATED.allows(1, 6));
+
+        let rule = Rule { repeats: Repeats::Prime, min_block: 2 };
+        assert!(rule.allows(3, 2));
+        assert!(!rule.allows(4, 2));
+        assert!(!rule.allows(3, 1));
+        assert!(!Rule { repeats: Repeats::AtMost(3), min_block: 1 }.allows(1, 4));
+    }
+
+    #[test]
+    fn test_parse() {
+        for repeats in [Repeats::Exactly(2), Repeats::AtLeast(3), Repeats::AtMost(4), Repeats::Prime] {
+            assert_eq!(repeats.to_string().parse(), Ok(repeats));
+        }
+        assert!("twice".parse::<Repeats>().is_err());
+        assert!("at-least-x".parse::<Repeats>().is_err());
+        assert!("sometimes-2".parse::<Repeats>().is_err());
+    }
+}
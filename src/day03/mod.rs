@@ -0,0 +1,97 @@
+
+use crate::{ParseError, Solution};
+
+/// Batteries turned on per bank in part 2, so the fewest a bank may hold.
+const BATTERIES: usize = 12;
+
+fn bank(n: usize, line: &str) -> Result<Vec<u8>, ParseError> {
+    if line.len() < BATTERIES {
+        let reason = format!("a bank needs at least {} batteries, found {}", BATTERIES, line.len());
+        return Err(ParseError::new(Day03::DAY, n, line.len(), line, reason));
+    }
+
+    line
+        .chars()
+        .enumerate()
+        .map(|(x, c)| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| ParseError::new(Day03::DAY, n, x, line, format!("{:?} is not a digit", c)))
+        })
+        .collect()
+}
+
+fn solve(numbers: &[u8]) -> u64 {
+    solve2(numbers, 2)
+}
+fn solve2(numbers: &[u8], k: usize) -> u64 {
+    let mut res = vec![];
+    let l = numbers.len();
+    let mut left_bound = 0;
+    while res.len() != k {
+        // l=10, k=5, |res|=2
+        let avail_count = l - (k - res.len() - 1);
+        let avail = &numbers[left_bound..avail_count];
+        let max = avail.iter().max().unwrap();
+        let i = avail.iter().position(|v| v == max).unwrap();
+        res.push(numbers[left_bound+i]);
+        left_bound += i + 1;
+    }
+    as_number(res.as_slice())
+}
+
+//
+fn as_number(nums: &[u8]) -> u64 {
+    nums.iter().fold(0u64, |acc, &e| 10*acc + (e as u64))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().enumerate().map(|(n, l)| bank(n, l)).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|b| solve(b)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|b| solve2(b, BATTERIES)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        assert_eq!(solve(&bank(0, "987654321111111").unwrap()), 98);
+        assert_eq!(solve(&bank(0, "811111111111119").unwrap()), 89);
+        assert_eq!(solve(&bank(0, "234234234234278").unwrap()), 78);
+        assert_eq!(solve(&bank(0, "818181911112111").unwrap()), 92);
+    }
+    #[test]
+    fn test_demo2() {
+        assert_eq!(solve2(&bank(0, "987654321111111").unwrap(), 12), 987654321111);
+        assert_eq!(solve2(&bank(0, "811111111111119").unwrap(), 12), 811111111119);
+        assert_eq!(solve2(&bank(0, "234234234234278").unwrap(), 12), 434234234278);
+        assert_eq!(solve2(&bank(0, "818181911112111").unwrap(), 12), 888911112111);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = bank(3, "98765x321000").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 6, "98765x321000"));
+
+        let err = bank(0, "987").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 4, "987"));
+        assert!(Day03::parse("987654321111\n\n").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+use log::debug;
+use crate::{ParseError, Solution};
+
+#[allow(clippy::identity_op)]
+fn neigh((x, y): &(i16, i16)) -> [(i16, i16); 8] {
+    [
+        (x-1, y-1),
+        (x-1, y+0),
+        (x-1, y+1),
+        (x+0, y-1),
+        (x+0, y+1),
+        (x+1, y-1),
+        (x+1, y+0),
+        (x+1, y+1),
+    ]
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Space;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.available().len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.clone().exhaustive().len()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Space {
+    blocks: HashSet<(i16, i16)>
+}
+
+impl Space {
+    fn available(&self) -> Vec<(i16, i16)> {
+        self.blocks.iter().filter_map(|&p| {
+            (
+                neigh(&p).iter().filter(|np| {self.blocks.contains(np)}).count()
+                <
+                4
+            ).then_some(p)
+        }).collect::<Vec<_>>()
+    }
+
+
+    fn exhaustive(mut self) -> HashSet<(i16, i16)> {
+        let mut total = HashSet::<(i16, i16)>::default();
+        let mut rem = self.available();
+        total.extend(rem.clone());
+        while !rem.is_empty() {
+            debug!("{} more removable", rem.len());
+            self.blocks.retain(|b| !rem.contains(b));
+            rem = self.available();
+            total.extend(rem.clone());
+        }
+
+        total
+    }
+
+
+}
+
+fn parse(input: &str) -> Result<Space, ParseError> {
+    let m: HashSet<_> = input.lines().enumerate().flat_map(|(y, line)| {
+        line.chars().enumerate().filter_map(move |(x, c)| match c {
+            '@' => Some(Ok((x as i16, y as i16))),
+            '.' => None,
+            _ => Some(Err(ParseError::new(Day04::DAY, y, x, line, format!("unexpected {:?}", c)))),
+        })
+    }).collect::<Result<_, _>>()?;
+
+    Ok(Space{ blocks: m })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let demo = include_str!("examples/demo.txt");
+
+        let m = parse(demo).unwrap();
+
+        assert_eq!(m.blocks.len(), 71);
+        assert_eq!(m.available().len(), 13);
+        assert_eq!(m.exhaustive().len(), 43);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..@@.\n@@#.@\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "@@#.@"));
+    }
+}
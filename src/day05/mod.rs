@@ -0,0 +1,113 @@
+use std::cmp::max;
+use std::ops::Range;
+use itertools::Itertools;
+use crate::error::number;
+use crate::{ParseError, Solution};
+
+#[derive(Debug)]
+pub struct State {
+    ranges: Vec<Range<u64>>,
+    ids: Vec<u64>,
+}
+
+impl State {
+    fn fresh(&self) -> usize {
+        self.ids.iter().filter(|id| {
+            self.ranges.iter().any(|r| r.contains(id))
+        }).count()
+    }
+
+    fn fresh2(&self) -> u64 {
+        let mut clear = self.ranges.clone();
+        clear.sort_by_key(|r| (r.start, r.end));
+        clear.dedup_by(|r, l| {
+            if l.end >= r.start {
+                l.end = max(r.end, l.end);
+                true
+            } else {
+                false
+            }
+        });
+
+        clear.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = State;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.fresh() as u64
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.fresh2()
+    }
+}
+
+fn parse(input: &str) -> Result<State, ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    let ranges: Vec<_> = lines.take_while_ref(|(_, l)| !l.is_empty()).collect();
+    let ids: Vec<_> = lines.skip(1).collect();
+
+    Ok(State{
+        ranges: ranges.iter().map(|&(n, r)| {
+            let Some((s, e)) = r.split_once("-") else {
+                return Err(ParseError::new(Day05::DAY, n, 0, r, "expected `start-end`"))
+            };
+            let start = number(Day05::DAY, n, 0, s)?;
+            let last = number::<u64>(Day05::DAY, n, s.len() + 1, e)?;
+            if start > last {
+                return Err(ParseError::new(Day05::DAY, n, 0, r, "range starts after it ends"))
+            }
+            let Some(end) = last.checked_add(1) else {
+                return Err(ParseError::new(Day05::DAY, n, s.len() + 1, e, format!("range must end below {}", u64::MAX)))
+            };
+            Ok(Range { start, end })
+        }).collect::<Result<_, _>>()?,
+        ids: ids.iter().map(|&(n, i)| number(Day05::DAY, n, 0, i)).collect::<Result<_, _>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let demo = include_str!("examples/demo.txt");
+
+        let state = parse(demo).unwrap();
+
+        // assert_eq!(state.fresh(), 3);
+        assert_eq!(state.fresh2(), 14);
+    }
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3-5\n10-1x\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "1x"));
+
+        let err = parse("3-5\n\n1\n\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 1, ""));
+
+        let err = parse("3..5\n").unwrap_err();
+        assert_eq!(err.to_string(), "day 5, line 1, column 1: expected `start-end` in \"3..5\"");
+
+        let err = parse("3-5\n5-3\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "5-3"));
+
+        let err = parse("0-18446744073709551615\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 3, "18446744073709551615"));
+        assert_eq!(parse("7-7\n\n7").unwrap().fresh2(), 1);
+    }
+}
@@ -0,0 +1,150 @@
+use std::iter;
+use itertools::Itertools;
+use crate::error::number;
+use crate::{ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Block>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|b| b.compute()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.iter().map(|b| b.compute_col()).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Block {
+    lines: Vec<String>,
+    op: char,
+}
+
+impl Block {
+    /// Both readings of the block, starting at `column`, have to be numbers.
+    fn check(&self, column: usize) -> Result<(), ParseError> {
+        for (n, l) in self.lines.iter().enumerate() {
+            let indent = l.len() - l.trim_start().len();
+            number::<u64>(Day06::DAY, n, column + indent, l.trim())?;
+        }
+        for col_i in 0..self.lines[0].len() {
+            let digits: String = self.lines.iter().map(|l| l.chars().nth(col_i).unwrap()).collect();
+            number::<u64>(Day06::DAY, 0, column + col_i, digits.trim())?;
+        }
+        Ok(())
+    }
+
+    fn compute(&self) -> u64 {
+        let add = self.op == '+';
+        self.lines.iter()
+            .map(|l| l.trim().parse::<u64>().unwrap())
+            .fold(
+                if add {0} else {1},
+                |acc, v| if add {acc+v} else {acc*v}
+            )
+    }
+
+    fn compute_col(&self) -> u64 {
+        let add = self.op == '+';
+
+        (0..self.lines.last().unwrap().len())
+            .map(
+                |col_i|
+                    self.lines.iter()
+                        .map(|l| l.chars().nth(col_i).unwrap())
+                        .collect::<Vec<_>>()
+            )
+            .map(|n|
+                n.iter().join("").trim().parse::<u64>().unwrap()
+            )
+            .fold(
+                if add {0} else {1},
+                |acc, v| if add {acc+v} else {acc*v}
+            )
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+    let lines: Vec<_> = input.lines().collect();
+    let Some((ops, blocks)) = lines.split_last() else {
+        return Err(ParseError::new(Day06::DAY, 0, 0, "", "no operator line"))
+    };
+    if blocks.is_empty() {
+        return Err(ParseError::new(Day06::DAY, 0, 0, ops, "no numbers above the operators"))
+    }
+    if let Some((i, c)) = ops.chars().enumerate().find(|(_, c)| !" +*".contains(*c)) {
+        return Err(ParseError::new(Day06::DAY, blocks.len(), i, ops, format!("unexpected operator {:?}", c)))
+    }
+
+    ops
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c != ' ')
+        .chain(vec![
+            (1+lines.iter().map(|l| l.len()).max().unwrap(),
+             '_',
+        )])
+        .tuple_windows::<(_, _)>()
+        .map(|((my_i, my_op), (next_i, _))| {
+            let block = Block {
+                // editors like to strip the trailing spaces
+                lines: blocks.iter().map(|l| {
+                    l.chars().chain(iter::repeat(' ')).skip(my_i).take(next_i-1-my_i).collect()
+                }).collect::<Vec<_>>(),
+                op: my_op,
+            };
+            block.check(my_i)?;
+            Ok(block)
+        }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let input = include_str!("examples/demo.txt");
+        let parsed = parse(input).unwrap();
+
+        assert_eq!(parsed.iter().map(|b| b.compute()).sum::<u64>(), 4277556);
+    }
+    #[test]
+    fn test_demo2() {
+        let input = include_str!("examples/demo.txt");
+        let parsed = parse(input).unwrap();
+
+        assert_eq!(parsed.iter().map(|b| b.compute_col()).sum::<u64>(), 3263827);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("12 3\n4x 5\n+  *").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "4x"));
+
+        let err = parse("12 3\n45 6\n+  /").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "+  /"));
+
+        let err = parse("12\n").unwrap_err();
+        assert_eq!(err.reason, "no numbers above the operators");
+    }
+
+    #[test]
+    fn test_stripped_trailing_spaces() {
+        let input = include_str!("examples/demo.txt").lines().map(str::trim_end).join("\n");
+        let parsed = parse(&input).unwrap();
+
+        assert_eq!(parsed.iter().map(|b| b.compute()).sum::<u64>(), 4277556);
+        assert_eq!(parsed.iter().map(|b| b.compute_col()).sum::<u64>(), 3263827);
+    }
+}
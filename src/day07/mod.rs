@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use log::trace;
+use crate::{ParseError, Solution};
+
+#[derive(Debug, PartialEq, PartialOrd, Hash, Ord, Eq, Copy, Clone)]
+struct Position(i32, i32);
+
+impl Position {
+    fn bellow(&self) -> Position {
+        let Self(x, y) = self;
+
+        Position(*x, *y+1)
+    }
+    fn sides(&self) -> Vec<Position> {
+        let Self(x, y) = self;
+        
+        vec![
+            Position(*x-1, *y),
+            Position(*x+1, *y),
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    splits: HashSet<Position>,
+    start: Position,
+    
+    used_splits: HashSet<Position>,
+    seen: HashSet<Position>,
+
+    max: Position,
+}
+
+impl State {
+    fn in_space(&self, p: Position) -> bool {
+        let Position(x, y) = p;
+        let Position(max_x, max_y) = self.max;
+        
+        x >= 0 && x <= max_x && y >= 0 && y <= max_y
+        
+    }
+    
+    fn run(&mut self) {
+        let mut discover = vec![self.start];
+        
+        while let Some(p) = discover.pop() {
+            if !self.in_space(p) {
+                continue
+            }
+            if self.seen.contains(&p) {
+                continue
+            }
+            self.seen.insert(p);
+            
+            let is_split = self.splits.contains(&p);
+            if is_split {
+                self.used_splits.insert(p);
+                discover.extend(p.sides());
+            } else {
+                discover.push(p.bellow());
+            }
+            trace!("\n{}", self);
+        }
+    }
+    
+    /// Timelines of the beam leaving the manifold, 0 without any splitter.
+    fn part2(&self) -> u64 {
+        let mut s: Vec<_> = self.splits.iter().filter(|Position(_, y)| *y > self.start.1).collect();
+        if s.is_empty() {
+            return 0
+        }
+        s.sort_by_key(|Position(x, y)| (y, x));
+        
+        let mut counts = HashMap::<i32, u64>::new();
+        counts.insert(self.start.0, 1);
+        
+        for split in s {
+            let &Position(x, _) = split;
+            // no beam reaches a splitter off every path
+            let curr = counts.get(&x).copied().unwrap_or(0);
+            trace!("dividing at {x} value={curr}");
+            for Position(neigh_x, _) in split.sides() {
+                trace!("adding {curr} to {neigh_x}");
+                counts.entry(neigh_x).and_modify(|v| *v += curr).or_insert(curr);
+            }
+            counts.entry(x).and_modify(|v| *v = 0);
+            trace!("{:?}", counts);
+        }
+        
+        counts.values().sum()
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Position(max_x, max_y) = self.max;
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                let pos = Position(x, y);
+                let ch = if self.start == pos {
+                    'S'
+                } else if self.used_splits.contains(&pos) || self.splits.contains(&pos) {
+                    '^'
+                } else if self.seen.contains(&pos) {
+                    '|'
+                } else {
+                    '.'
+                };
+                write!(f, "{}", ch)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+
+fn parse(input: &str) -> Result<State, ParseError> {
+    let mut start = None;
+    let mut splits = HashSet::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                'S' => start = Some(Position(x as i32, y as i32)),
+                '^' => { splits.insert(Position(x as i32, y as i32)); }
+                '.' => {}
+                _ => return Err(ParseError::new(Day07::DAY, y, x, line, format!("unexpected {:?}", c))),
+            }
+        }
+    }
+
+    let lines = input.lines().collect::<Vec<_>>();
+    let first = lines.first().copied().unwrap_or_default();
+    let Some(start) = start else {
+        return Err(ParseError::new(Day07::DAY, 0, 0, first, "no start `S`"))
+    };
+    let max = Position(
+        first.len() as i32 - 1,
+        lines.len() as i32 - 1,
+    );
+
+    Ok(State{
+        start,
+        splits,
+        max,
+
+        used_splits: Default::default(),
+        seen: Default::default(),
+    })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = State;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        let mut state = input.clone();
+        state.run();
+        state.used_splits.len() as u64
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.part2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let input = include_str!("examples/demo.txt");
+        
+        let state = parse(input).unwrap();
+        // state.run();
+        // assert_eq!(state.used_splits.len(), 21);
+        
+        assert_eq!(state.part2(), 40);
+    }
+    #[test]
+    fn test_simple() {
+        let input = "\
+...S...
+...^...
+..^.^..
+.......
+";
+        
+        let mut state = parse(input).unwrap();
+        state.run();
+        assert_eq!(state.used_splits.len(), 3);
+        assert_eq!(state.part2(), 4);
+    }
+
+    #[test]
+    fn test_unreached_splitters() {
+        assert_eq!(parse("...S...\n.......\n.^...^.\n").unwrap().part2(), 1);
+        assert_eq!(parse("...S...\n.......\n").unwrap().part2(), 0);
+        assert_eq!(parse("...^...\n...S...\n.......\n").unwrap().part2(), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..S..\n..^x.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "..^x."));
+
+        let err = parse(".....\n..^..\n").unwrap_err();
+        assert_eq!(err.to_string(), "day 7, line 1, column 1: no start `S` in \".....\"");
+    }
+}
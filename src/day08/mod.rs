@@ -0,0 +1,229 @@
+
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use log::{debug, trace};
+use crate::error::number;
+use crate::{ParseError, Solution};
+
+/// Number of closest pairs joined for part 1, unless the input says otherwise.
+const CONNECTIONS: usize = 1000;
+
+#[derive(Debug, PartialEq, PartialOrd, Hash, Ord, Eq, Copy, Clone)]
+pub struct Point(u32, u32, u32);
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:},{:},{:}", self.0, self.1, self.2)
+    }
+}
+
+impl Point {
+    fn distance(&self, Point(x, y, z): &Point) -> u64 {
+        let Point(mx, my, mz) = self;
+        
+        (x.abs_diff(*mx) as u64).pow(2) +
+        (y.abs_diff(*my) as u64).pow(2) +
+        (z.abs_diff(*mz) as u64).pow(2)
+    
+    }
+}
+
+
+/// The junction boxes and how many of the closest pairs part 1 joins.
+#[derive(Debug, Clone)]
+pub struct Playground {
+    pub points: Vec<Point>,
+    pub connections: usize,
+}
+
+/// An optional first line `connections: N` overrides [`CONNECTIONS`], as the
+/// examples join fewer pairs than the puzzle.
+fn parse_playground(input: &str) -> Result<Playground, ParseError> {
+    let first = input.lines().next().unwrap_or_default();
+    let Some(count) = first.strip_prefix("connections:") else {
+        return Ok(Playground { points: parse(input)?, connections: CONNECTIONS });
+    };
+
+    let column = first.len() - count.trim_start().len();
+    let connections = number(Day08::DAY, 0, column, count.trim())?;
+    let points = parse(input.split_once('\n').map_or("", |(_, rest)| rest))
+        .map_err(|err| ParseError { line: err.line + 1, ..err })?;
+    Ok(Playground { points, connections })
+}
+
+fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    input.lines().enumerate().map(|(n, l)| {
+        let mut column = 0;
+        let values = l.split(",").map(|v| {
+            let value = number(Day08::DAY, n, column, v);
+            column += v.len() + 1;
+            value
+        }).collect::<Result<Vec<u32>, _>>()?;
+
+        let [x, y, z] = values[..] else {
+            return Err(ParseError::new(Day08::DAY, n, 0, l, "expected `x,y,z`"))
+        };
+        Ok(Point(x, y, z))
+    }).collect()
+}
+
+/// Every pair of boxes by index, the closest last.
+fn pairs(points: &[Point]) -> Vec<(usize, usize)> {
+    (0..points.len())
+        .tuple_combinations()
+        .sorted_by_key(|&(i, j)| Reverse(points[i].distance(&points[j])))
+        .collect()
+}
+
+/// The circuits junction boxes belong to, by index so equal boxes stay apart.
+struct Circuits {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl Circuits {
+    fn new(boxes: usize) -> Circuits {
+        Circuits { parent: (0..boxes).collect(), size: vec![1; boxes], count: boxes }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Connects `i` and `j`, false when they already shared a circuit.
+    fn join(&mut self, i: usize, j: usize) -> bool {
+        let (mut a, mut b) = (self.find(i), self.find(j));
+        if a == b {
+            return false
+        }
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    /// Sizes of all circuits, largest first.
+    fn sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|i| self.size[i])
+            .sorted_by_key(|&size| Reverse(size))
+            .collect()
+    }
+}
+
+fn solve(points: &[Point], to_connect: usize) -> usize {
+    let mut pairs = pairs(points);
+    debug!("{} pairs, connecting {}", pairs.len(), to_connect);
+
+    let mut circuits = Circuits::new(points.len());
+    for _ in 0..to_connect {
+        let Some((i, j)) = pairs.pop() else {
+            break
+        };
+        let joined = circuits.join(i, j);
+        trace!("{}\t{}: {}", points[i], points[j], if joined { "joined" } else { "already connected" });
+    }
+
+    circuits.sizes().into_iter().take(3).product()
+}
+
+/// The x product of the pair that finally joins everything into a single
+/// circuit, 0 when no pair does as there are fewer than two boxes.
+fn solve2(points: &[Point]) -> usize {
+    let mut pairs = pairs(points);
+    debug!("{} pairs", pairs.len());
+
+    let mut circuits = Circuits::new(points.len());
+    while let Some((i, j)) = pairs.pop() {
+        if circuits.join(i, j) {
+            trace!("{} and {}: {} circuits left", points[i], points[j], circuits.count);
+            if circuits.count == 1 {
+                return points[i].0 as usize * points[j].0 as usize
+            }
+        }
+    }
+    0
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_playground(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        solve(&input.points, input.connections)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        solve2(&input.points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo() {
+        let demo = include_str!("examples/demo.txt");
+        
+        let playground = parse_playground(demo).unwrap();
+        assert_eq!(playground.points.len(), 20);
+        assert_eq!(playground.connections, 10);
+        
+        assert_eq!(solve(&playground.points, playground.connections), 40);
+        assert_eq!(solve2(&playground.points), 25272);
+    }
+
+    #[test]
+    fn test_connections() {
+        let playground = parse_playground("1,1,1\n2,2,2\n9,9,9\n").unwrap();
+        assert_eq!(playground.connections, CONNECTIONS);
+        // more connections than pairs, and fewer than three circuits
+        assert_eq!(solve(&playground.points, playground.connections), 3);
+        assert_eq!(solve(&playground.points, 1), 2);
+
+        let err = parse_playground("connections: x\n1,2,3\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 14, "x"));
+        let err = parse_playground("connections: 2\n1,2,3\n1,2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "1,2"));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(solve2(&parse("1,2,3\n").unwrap()), 0);
+        assert_eq!(solve2(&[]), 0);
+
+        // equal boxes are still boxes of their own
+        let points = parse("1,0,0\n1,0,0\n5,0,0\n").unwrap();
+        assert_eq!(solve(&points, 1), 2);
+        assert_eq!(solve(&points, 3), 3);
+        assert_eq!(solve2(&points), 5);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1,2,3\n4,-5,6\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "-5"));
+
+        let err = parse("1,2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "1,2"));
+    }
+}
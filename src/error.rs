@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Malformed puzzle input, located to the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line of the input.
+    pub line: usize,
+    /// 1-based column within `line`, in characters.
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// `line` and `column` are 0-based, as they come out of `enumerate`.
+    pub fn new(day: u8, line: usize, column: usize, text: &str, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: line + 1,
+            column: column + 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} in {:?}",
+            self.day, self.line, self.column, self.reason, self.text,
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, found at 0-based `line` and `column`, as a number.
+pub(crate) fn number<T>(day: u8, line: usize, column: usize, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|err: T::Err| ParseError::new(day, line, column, text, err.to_string()))
+}
@@ -0,0 +1,98 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Directory holding one `dayNN` file per day, e.g. inputs of another account.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Loads the input of `day`.
+///
+/// An explicit `path` wins, `-` meaning stdin. Otherwise the first existing of
+/// [`candidates`] is used.
+pub fn load(day: u8, path: Option<&str>) -> anyhow::Result<String> {
+    match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map_err(|err| anyhow::format_err!("cannot read stdin: {}", err))?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path)
+            .map_err(|err| anyhow::format_err!("cannot read {}: {}", path, err)),
+        None => {
+            let tried = candidates(day, |var| env::var(var).ok());
+            tried
+                .iter()
+                .find_map(|p| fs::read_to_string(p).ok())
+                .ok_or_else(|| anyhow::format_err!(
+                    "no input for day {}, tried: {} (pass a path or set {})",
+                    day,
+                    tried.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "),
+                    INPUT_DIR_VAR,
+                ))
+        }
+    }
+}
+
+/// Places searched for the input of `day`, in order:
+/// `$AOC_INPUT_DIR/dayNN`, the user cache directory and `src/dayNN/input` of
+/// both the working directory and the repository.
+pub fn candidates(day: u8, var: impl Fn(&str) -> Option<String>) -> Vec<PathBuf> {
+    let name = format!("day{:02}", day);
+    let cache = var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".cache")));
+
+    let mut paths = vec![];
+    if let Some(dir) = var(INPUT_DIR_VAR) {
+        paths.push(PathBuf::from(dir).join(&name));
+    }
+    if let Some(cache) = cache {
+        paths.push(cache.join("aoc-2025").join(&name));
+    }
+    paths.push(PathBuf::from("src").join(&name).join("input"));
+
+    let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src").join(&name).join("input");
+    if !paths.contains(&repo) {
+        paths.push(repo);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates() {
+        let paths = candidates(7, |var| match var {
+            INPUT_DIR_VAR => Some("/inputs".into()),
+            "HOME" => Some("/home/me".into()),
+            _ => None,
+        });
+
+        assert_eq!(paths[0], PathBuf::from("/inputs/day07"));
+        assert_eq!(paths[1], PathBuf::from("/home/me/.cache/aoc-2025/day07"));
+        assert_eq!(paths[2], PathBuf::from("src/day07/input"));
+        assert!(paths.last().unwrap().ends_with("src/day07/input"));
+    }
+
+    #[test]
+    fn test_candidates_xdg() {
+        let paths = candidates(1, |var| match var {
+            "XDG_CACHE_HOME" => Some("/cache".into()),
+            "HOME" => Some("/home/me".into()),
+            _ => None,
+        });
+
+        assert_eq!(paths[0], PathBuf::from("/cache/aoc-2025/day01"));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let err = load(1, Some("/nonexistent/day01")).unwrap_err();
+        assert!(err.to_string().starts_with("cannot read /nonexistent/day01"));
+    }
+}
@@ -0,0 +1,206 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use alloc::Usage;
+
+pub mod alloc;
+pub mod answers;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+mod error;
+pub mod input;
+pub mod logger;
+
+pub use error::ParseError;
+
+/// One day of the puzzle: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    /// Day of the puzzle, as in `src/dayNN`.
+    const DAY: u8;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow::format_err!("no part {}, expected 1 or 2", value)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Part::One => "1",
+            Part::Two => "2",
+        })
+    }
+}
+
+/// Answer of a single part together with the time and memory it took, parsing excluded.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub usage: Usage,
+}
+
+/// Everything produced by solving one day on one input.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    /// [`input_hash`] of the input solved.
+    pub input_hash: String,
+    pub parse: Duration,
+    pub parse_usage: Usage,
+    pub parts: Vec<PartResult>,
+}
+
+impl Report {
+    /// One JSON object per part, `duration` in seconds; with `mem` also the
+    /// part's `peak` bytes and `allocations`.
+    pub fn json_lines(&self, mem: bool) -> Vec<String> {
+        self.parts.iter().map(|result| {
+            let usage = match mem {
+                true => format!(r#", "peak": {}, "allocations": {}"#, result.usage.peak, result.usage.allocations),
+                false => String::new(),
+            };
+            format!(
+                r#"{{"day": {}, "part": {}, "answer": {}, "duration": {}, "input_hash": "{}"{}}}"#,
+                self.day,
+                result.part,
+                json_string(&result.answer),
+                result.elapsed.as_secs_f64(),
+                self.input_hash,
+                usage,
+            )
+        }).collect()
+    }
+}
+
+/// `s` as a quoted JSON string.
+pub fn json_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+/// Parses `input` once and answers the requested `parts` of `S`, timing each
+/// step and recording its allocations.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> anyhow::Result<Report> {
+    let start = Instant::now();
+    let (parsed, parse_usage) = alloc::measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts.iter().map(|&part| {
+        let start = Instant::now();
+        let (answer, usage) = alloc::measure(|| match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        });
+        let elapsed = start.elapsed();
+
+        // formatting the answer is not part of solving it
+        PartResult { part, answer: answer.to_string(), elapsed, usage }
+    }).collect();
+
+    Ok(Report { day: S::DAY, input_hash: input_hash(input), parse, parse_usage, parts })
+}
+
+/// Type-erased [`solve`], so a day can be picked at runtime.
+pub type Solver = fn(&str, &[Part]) -> anyhow::Result<Report>;
+
+/// Every implemented day, in order.
+pub const DAYS: [(u8, Solver); 8] = [
+    (day01::Day01::DAY, solve::<day01::Day01>),
+    (day02::Day02::DAY, solve::<day02::Day02>),
+    (day03::Day03::DAY, solve::<day03::Day03>),
+    (day04::Day04::DAY, solve::<day04::Day04>),
+    (day05::Day05::DAY, solve::<day05::Day05>),
+    (day06::Day06::DAY, solve::<day06::Day06>),
+    (day07::Day07::DAY, solve::<day07::Day07>),
+    (day08::Day08::DAY, solve::<day08::Day08>),
+];
+
+pub fn solver(day: u8) -> Option<Solver> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
+
+/// Stable fingerprint of an input (64-bit FNV-1a, hex), used to tell inputs of
+/// different accounts apart.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |acc, b| {
+        (acc ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Entry point of the `dayNN` binaries: loads the input named by the first
+/// argument (see [`input::load`]), solves both parts and prints one answer per line.
+///
+/// `-v` and `-vv` anywhere among the arguments turn on diagnostics.
+pub fn run<S: Solution>() -> anyhow::Result<()> {
+    let (flags, paths): (Vec<_>, Vec<_>) = env::args()
+        .skip(1)
+        .partition(|a| a.starts_with("-v") && a[1..].chars().all(|c| c == 'v'));
+    logger::init(flags.iter().map(|f| f.len() as u8 - 1).sum());
+
+    let input = input::load(S::DAY, paths.first().map(String::as_str))?;
+
+    for result in solve::<S>(&input, &Part::ALL)?.parts {
+        println!("{}", result.answer);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_lines() {
+        let report = Report {
+            day: 7,
+            input_hash: input_hash(""),
+            parse: Duration::ZERO,
+            parse_usage: Usage::default(),
+            parts: vec![PartResult { part: Part::Two, answer: "a\"b".into(), elapsed: Duration::from_millis(1500), usage: Usage::default() }],
+        };
+
+        assert_eq!(
+            report.json_lines(false),
+            vec![r#"{"day": 7, "part": 2, "answer": "a\"b", "duration": 1.5, "input_hash": "cbf29ce484222325"}"#],
+        );
+        assert!(report.json_lines(true)[0].ends_with(r#""input_hash": "cbf29ce484222325", "peak": 0, "allocations": 0}"#));
+        assert_eq!(json_string("a\\b\n"), r#""a\\b\n""#);
+    }
+}
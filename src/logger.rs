@@ -0,0 +1,49 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes diagnostics of the day modules to stderr, so answers on stdout stay clean.
+struct Stderr;
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Level for the number of `-v` flags given: warnings only by default,
+/// `-v` for debug and `-vv` for trace output.
+pub fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Installs the stderr logger; later calls only change the level.
+pub fn init(verbosity: u8) {
+    let _ = log::set_logger(&Stderr);
+    log::set_max_level(level(verbosity));
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), LevelFilter::Warn);
+        assert_eq!(level(1), LevelFilter::Debug);
+        assert_eq!(level(2), LevelFilter::Trace);
+        assert_eq!(level(5), LevelFilter::Trace);
+        assert!(Level::Trace > level(1));
+    }
+}
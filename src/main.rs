@@ -0,0 +1,217 @@
+use clap::{Parser, Subcommand};
+use aoc_2025::alloc::{self, Counting, Usage};
+use aoc_2025::answers::{self, Answers, Status};
+use aoc_2025::{input, json_string, logger, solver, Part, Report, DAYS};
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+/// Runs any day of Advent of Code 2025.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print one JSON record per part instead of plain text; with `--mem` the
+    /// records carry the part's peak memory and allocation count.
+    #[arg(long, global = true)]
+    json: bool,
+    /// Table with peak memory and allocation count of parse and each part; only
+    /// counted with this flag, as counting slows every allocation down.
+    #[arg(long, global = true)]
+    mem: bool,
+    /// Diagnostics on stderr: `-v` for debug, `-vv` for trace.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, printing one answer per line.
+    Run {
+        #[arg(short, long)]
+        day: u8,
+        /// Only this part; both when omitted.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` for stdin; looked up by day when omitted.
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Solve every day and print a table of answers and timings.
+    All,
+    /// Solve every day and compare against the recorded answers.
+    Verify {
+        /// Defaults to `answers.txt` in the repository.
+        #[arg(short, long)]
+        answers: Option<String>,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    logger::init(cli.verbose);
+    if cli.mem {
+        alloc::enable();
+    }
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let solve = solver(day)
+                .ok_or_else(|| anyhow::format_err!("day {} is not implemented", day))?;
+            let parts = match part {
+                Some(p) => vec![Part::try_from(p)?],
+                None => Part::ALL.to_vec(),
+            };
+            let input = input::load(day, input.as_deref())?;
+
+            let report = solve(&input, &parts)?;
+            if cli.json {
+                print_json(&report, cli.mem);
+            } else if cli.mem {
+                print_header(true);
+                print_rows(&report, true);
+            } else {
+                for result in report.parts {
+                    println!("{}", result.answer);
+                }
+            }
+        }
+        Command::All => {
+            if !cli.json {
+                print_header(cli.mem);
+            }
+            let mut broken = 0;
+
+            for (day, solve) in DAYS {
+                let input = match input::load(day, None) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        continue
+                    }
+                };
+                let report = match solve(&input, &Part::ALL) {
+                    Ok(report) => report,
+                    Err(err) => {
+                        broken += 1;
+                        eprintln!("day {:02}: {}", day, err);
+                        continue
+                    }
+                };
+                if cli.json {
+                    print_json(&report, cli.mem);
+                } else {
+                    print_rows(&report, cli.mem);
+                }
+            }
+
+            if broken > 0 {
+                anyhow::bail!("{} days could not be solved", broken);
+            }
+        }
+        Command::Verify { answers } => {
+            let answers = Answers::load(answers.as_deref().unwrap_or(answers::DEFAULT_PATH))?;
+            let (mut failed, mut broken) = (0, 0);
+
+            for (day, solve) in DAYS {
+                let input = match input::load(day, None) {
+                    Ok(input) => input,
+                    Err(_) => {
+                        for part in Part::ALL {
+                            print_verdict(cli.json, day, Some(part), "missing input", None);
+                        }
+                        continue
+                    }
+                };
+
+                let report = match solve(&input, &Part::ALL) {
+                    Ok(report) => report,
+                    Err(err) => {
+                        broken += 1;
+                        print_verdict(cli.json, day, None, "error", Some(("error", &err.to_string())));
+                        continue
+                    }
+                };
+
+                for (part, status) in answers.check(&report) {
+                    match status {
+                        Status::Pass => print_verdict(cli.json, day, Some(part), "pass", None),
+                        Status::Missing => print_verdict(cli.json, day, Some(part), "missing", None),
+                        Status::Fail { expected } => {
+                            failed += 1;
+                            print_verdict(cli.json, day, Some(part), "fail", Some(("expected", &expected)));
+                        }
+                    }
+                }
+            }
+
+            if failed > 0 || broken > 0 {
+                anyhow::bail!("{} answers do not match, {} days could not be solved", failed, broken);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_header(mem: bool) {
+    print!("{:>3}  {:>5}  {:>20}  {:>12}", "day", "part", "answer", "time");
+    if mem {
+        print!("  {:>10}  {:>10}", "peak", "allocs");
+    }
+    println!();
+}
+
+/// With `mem`, parsing gets a row of its own.
+fn print_rows(report: &Report, mem: bool) {
+    let row = |part: &str, answer: &str, elapsed, usage: Usage| {
+        print!("{:>3}  {:>5}  {:>20}  {:>12.3?}", report.day, part, answer, elapsed);
+        if mem {
+            print!("  {:>10}  {:>10}", format_bytes(usage.peak), usage.allocations);
+        }
+        println!();
+    };
+
+    if mem {
+        row("parse", "", report.parse, report.parse_usage);
+    }
+    for result in &report.parts {
+        row(&result.part.to_string(), &result.answer, result.elapsed, result.usage);
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1048576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+fn print_json(report: &Report, mem: bool) {
+    for line in report.json_lines(mem) {
+        println!("{}", line);
+    }
+}
+
+/// One verify result, as text or as a JSON record; `part` is `None` when the
+/// whole day failed, `detail` is the expected answer or the error.
+fn print_verdict(json: bool, day: u8, part: Option<Part>, status: &str, detail: Option<(&str, &str)>) {
+    if json {
+        let part = part.map_or(String::new(), |part| format!(r#", "part": {}"#, part));
+        let detail = detail.map_or(String::new(), |(key, value)| format!(r#", "{}": {}"#, key, json_string(value)));
+        println!(r#"{{"day": {}{}, "status": "{}"{}}}"#, day, part, status, detail);
+        return
+    }
+
+    let label = match (status, detail) {
+        ("fail", Some((_, expected))) => format!("FAIL (expected {})", expected),
+        ("error", Some((_, err))) => format!("ERROR ({})", err),
+        _ => status.to_string(),
+    };
+    match part {
+        Some(part) => println!("day {:02} part {}: {}", day, part, label),
+        None => println!("day {:02}: {}", day, label),
+    }
+}
@@ -0,0 +1,23 @@
+//! One test per part of every example input; adding an example is dropping
+//! `NAME.txt` and `NAME.answers` into `src/dayNN/examples`.
+//!
+//! The answers file holds a `part answer` line for each part to check, e.g.
+//!
+//! ```text
+//! 1 3
+//! 2 6
+//! ```
+
+use aoc_2025::*;
+
+macro_rules! example {
+    ($test:ident, $day:ty, $part:ident, $path:expr, $answer:expr) => {
+        #[test]
+        fn $test() {
+            let report = solve::<$day>(include_str!($path), &[Part::$part]).unwrap();
+            assert_eq!(report.parts[0].answer, $answer);
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));